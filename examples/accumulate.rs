@@ -0,0 +1,23 @@
+use rust_journey::accumulate::Accumulate;
+
+fn main() {
+    // === SAME METHOD, DIFFERENT BEHAVIOUR ===
+    println!("i32:    {}", i32::accumulate(&[1, 2, 3]));    // 6
+    println!("&str:   {}", <&str>::accumulate(&["a", "b"])); // ab
+
+    let parts = vec![String::from("Rust"), String::from("acean")];
+    println!("String: {}", String::accumulate(&parts)); // Rustacean
+
+    // === GENERIC OVER ANY ACCUMULATOR ===
+    println!("\ntotal: {}", combine(&[10, 20, 30]));
+    println!("joined: {}", combine(&["x", "y", "z"]));
+
+    // === EMPTY INPUT ===
+    println!("\nempty i32: {}", i32::accumulate(&[]));        // 0
+    println!("empty &str: {:?}", <&str>::accumulate(&[]));    // ""
+}
+
+// T::Output is whatever the impl chose — i32 for numbers, String for text
+fn combine<T: Accumulate>(items: &[T]) -> T::Output {
+    T::accumulate(items)
+}
@@ -0,0 +1,22 @@
+use rust_journey::approx::approx_eq;
+use rust_journey::assert_approx;
+
+fn main() {
+    // === THE CLASSIC PITFALL ===
+    let sum = 0.1 + 0.2;
+    println!("0.1 + 0.2 = {:.17}", sum);       // 0.30000000000000004
+    println!("0.1 + 0.2 == 0.3? {}", sum == 0.3); // false!
+
+    // === THE FIX ===
+    println!("approx_eq? {}", approx_eq(sum, 0.3, 1e-9)); // true
+
+    // === RELATIVE TOLERANCE FOR BIG NUMBERS ===
+    let big = 1e20;
+    println!("\n1e20 vs 1e20 + 1000: {}", approx_eq(big, big + 1000.0, 1e-9)); // true
+    println!("1e20 vs 1.1e20:      {}", approx_eq(big, 1.1e20, 1e-9));        // false
+
+    // === THE MACRO ===
+    assert_approx!(sum, 0.3);
+    assert_approx!(10.0 / 3.0 * 3.0, 10.0);
+    println!("\nassert_approx! checks passed");
+}
@@ -0,0 +1,21 @@
+use rust_journey::argmin::{arg_by_key, argmax, argmin};
+
+fn main() {
+    // === SHORTEST NAME ===
+    let names = ["Alexandra", "Bo", "Christopher", "Di", "Eve"];
+    if let Some(i) = arg_by_key(&names, |n| n.len()) {
+        println!("shortest name is {:?} at index {}", names[i], i); // "Bo" at 1 — first of the ties
+    }
+
+    // === WHICH DAY? ===
+    let days = ["Mon", "Tue", "Wed", "Thu", "Fri"];
+    let temps = [18.5, 21.0, 16.25, 23.5, 19.0];
+    let coldest = argmin(&temps).unwrap();
+    let warmest = argmax(&temps).unwrap();
+    println!("\ncoldest: {} ({}°C)", days[coldest], temps[coldest]); // Wed
+    println!("warmest: {} ({}°C)", days[warmest], temps[warmest]); // Thu
+
+    // === NOTHING TO PICK FROM ===
+    let none: [u8; 0] = [];
+    println!("\nargmin of []: {:?}", argmin(&none)); // None
+}
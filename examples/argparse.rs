@@ -0,0 +1,36 @@
+use rust_journey::argparse::ArgParser;
+
+fn main() {
+    let parser = ArgParser::new()
+        .bool_flag("--verbose")
+        .string_flag("--name", "world")
+        .int_flag("--count", 1);
+
+    // === SIMULATED COMMAND LINES ===
+    // In a real program: let argv: Vec<String> = std::env::args().skip(1).collect();
+    let command_lines = [
+        "",
+        "--name ferris --count 3",
+        "--verbose notes.txt --count 2 extra.txt",
+        "--count three",
+        "--name",
+        "--colour blue",
+    ];
+
+    for line in command_lines {
+        let argv: Vec<String> = line.split_whitespace().map(String::from).collect();
+        println!("$ greet {}", line);
+        match parser.parse(&argv) {
+            Ok(args) => {
+                let name = args.string("--name").unwrap();
+                for _ in 0..args.int("--count").unwrap() {
+                    println!("  hello, {}!", name);
+                }
+                if args.flag("--verbose").unwrap() {
+                    println!("  (verbose) files: {:?}", args.positional);
+                }
+            }
+            Err(e) => println!("  error: {}", e),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use rust_journey::ast::{eval, parse};
+
+fn main() {
+    // === PARSE, SHOW THE TREE, EVALUATE ===
+    for input in [
+        "2 + 3",
+        "2 + 3 * 4",
+        "(2 + 3) * 4",
+        "10 / 4 - 0.5",
+        "8 - 2 - 1",
+    ] {
+        match parse(input) {
+            Ok(tree) => {
+                println!("{}", input);
+                println!("  tree:   {:?}", tree);
+                println!("  result: {}", eval(&tree));
+            }
+            Err(e) => println!("{} → error: {}", input, e),
+        }
+    }
+
+    // === ERRORS ===
+    println!();
+    for bad in ["2 +", "(1 + 2", "3 3", "4 * $"] {
+        println!("{:>8} → {}", bad, parse(bad).unwrap_err());
+    }
+}
@@ -0,0 +1,34 @@
+use rust_journey::backoff::Backoff;
+use std::time::Duration;
+
+fn main() {
+    let strategies = [
+        ("fixed", Backoff::Fixed(Duration::from_millis(500))),
+        ("linear", Backoff::Linear(Duration::from_millis(200))),
+        (
+            "exponential",
+            Backoff::Exponential {
+                base: Duration::from_millis(100),
+                cap: Duration::from_secs(2),
+            },
+        ),
+    ];
+
+    // === DELAY SCHEDULE FOR 6 ATTEMPTS ===
+    for (name, strategy) in &strategies {
+        let schedule: Vec<String> = strategy
+            .schedule(6)
+            .iter()
+            .map(|d| format!("{}ms", d.as_millis()))
+            .collect();
+        println!("{:<12} {}", name, schedule.join(", "));
+    }
+    // exponential: 100, 200, 400, 800, 1600, then clamped to 2000
+
+    // === TOTAL TIME SPENT WAITING ===
+    println!();
+    for (name, strategy) in &strategies {
+        let total: Duration = strategy.schedule(6).iter().sum();
+        println!("{:<12} waits {:?} in total", name, total);
+    }
+}
@@ -0,0 +1,24 @@
+use rust_journey::base64::{decode, encode};
+
+fn main() {
+    // === ENCODE ===
+    let message = "Hello, Rust! 🦀";
+    let encoded = encode(message.as_bytes());
+    println!("original: {}", message);
+    println!("encoded:  {}", encoded);
+
+    // === DECODE BACK ===
+    match decode(&encoded) {
+        Ok(bytes) => println!("decoded:  {}", String::from_utf8_lossy(&bytes)),
+        Err(e) => println!("error: {}", e),
+    }
+
+    // === THE THREE PADDING CASES ===
+    println!();
+    for s in ["Man", "Ma", "M"] {
+        println!("{:<3} → {}", s, encode(s.as_bytes())); // TWFu, TWE=, TQ==
+    }
+
+    // === INVALID INPUT ===
+    println!("\n{:?}", decode("not base64!"));
+}
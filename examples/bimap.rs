@@ -0,0 +1,24 @@
+use rust_journey::bimap::BiMap;
+
+fn main() {
+    // === COUNTRY ↔ CODE ===
+    let mut countries = BiMap::new();
+    countries.insert("France", "FR");
+    countries.insert("Japan", "JP");
+    countries.insert("Brazil", "BR");
+
+    // Look up from either side
+    println!("Japan → {:?}", countries.get_by_left(&"Japan")); // Some("JP")
+    println!("BR → {:?}", countries.get_by_right(&"BR"));      // Some("Brazil")
+
+    // === ONE-TO-ONE IS ENFORCED ===
+    // Re-pairing France drops the old "FR" entry
+    countries.insert("France", "FRA");
+    println!("\nFrance → {:?}", countries.get_by_left(&"France")); // Some("FRA")
+    println!("FR → {:?}", countries.get_by_right(&"FR"));          // None
+
+    // === REMOVAL ===
+    countries.remove_by_left(&"Brazil");
+    println!("\nafter removing Brazil: {} pairs", countries.len());
+    println!("BR → {:?}", countries.get_by_right(&"BR")); // None
+}
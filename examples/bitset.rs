@@ -0,0 +1,43 @@
+use rust_journey::bitset::BitSet;
+
+fn main() {
+    // === WHICH USERS WERE ACTIVE EACH DAY? ===
+    let monday_ids = [3, 17, 42, 63, 64, 99, 150];
+    let tuesday_ids = [17, 42, 64, 88, 150, 151];
+
+    let mut monday = BitSet::new();
+    let mut tuesday = BitSet::new();
+    for id in monday_ids {
+        monday.set(id);
+    }
+    for id in tuesday_ids {
+        tuesday.set(id);
+    }
+
+    println!("monday:  {:?}", monday.iter().collect::<Vec<_>>());
+    println!("tuesday: {:?}", tuesday.iter().collect::<Vec<_>>());
+    println!("was 63 active monday? {}", monday.contains(63)); // true
+
+    // === SET OPERATIONS, 64 IDS PER INSTRUCTION ===
+    let mut both = monday.clone();
+    both.intersect_with(&tuesday);
+    println!("\nboth days:   {:?}", both.iter().collect::<Vec<_>>()); // [17, 42, 64, 150]
+
+    let mut either = monday.clone();
+    either.union_with(&tuesday);
+    println!("either day:  {} users", either.count_ones()); // 9
+
+    // A user deletes their account
+    either.clear(99);
+    println!("after removing 99: {} users", either.count_ones()); // 8
+
+    // === SIZE ===
+    // Largest id is 151, so 3 words hold every possible id 0..192 in
+    // 24 bytes. A HashSet<usize> needs 8+ bytes for *each* member, plus
+    // hashing overhead — but a BitSet holding only id 1_000_000 would need
+    // 15_625 words, so it suits dense, small ids.
+    println!(
+        "\nids 0..192 fit in {} bytes",
+        3 * std::mem::size_of::<u64>()
+    );
+}
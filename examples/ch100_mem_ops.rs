@@ -0,0 +1,82 @@
+use std::mem;
+
+fn main() {
+    // === mem::swap — EXCHANGE TWO VALUES ===
+    // Both sides are moved, never cloned — cheap even for big Strings/Vecs
+    let mut a = String::from("left");
+    let mut b = String::from("right");
+    mem::swap(&mut a, &mut b);
+    println!("a = {}, b = {}", a, b); // a = right, b = left
+
+    // Swapping two elements of the same Vec: `mem::swap(&mut v[0], &mut v[2])`
+    // won't compile — that's two &mut borrows of v at once. Split the slice
+    // so each half is borrowed separately.
+    let mut names = vec![String::from("ann"), String::from("bo"), String::from("cy")];
+    swap_elements(&mut names, 0, 2);
+    println!("swapped: {:?}", names); // ["cy", "bo", "ann"]
+    // (slices have .swap(i, j) built in — this is what it does for you)
+    names.swap(0, 2);
+    println!("and back: {:?}", names);
+
+    // === mem::replace — PUT A NEW VALUE IN, GET THE OLD ONE OUT ===
+    let mut status = String::from("pending");
+    let old = mem::replace(&mut status, String::from("done"));
+    println!("\nwas {}, now {}", old, status); // was pending, now done
+
+    // === mem::take — LIKE replace WITH Default::default() ===
+    let mut buffer = vec![1, 2, 3];
+    let drained = mem::take(&mut buffer);
+    println!("took {:?}, left {:?}", drained, buffer); // took [1, 2, 3], left []
+
+    // Taking a String leaves an empty String behind, not garbage
+    let mut title = String::from("Draft");
+    let moved = mem::take(&mut title);
+    println!("took {:?}, left {:?} (len {})", moved, title, title.len()); // "Draft", "", 0
+
+    // === MOVING A FIELD OUT FROM BEHIND &mut ===
+    let mut job = Job {
+        name: String::from("backup"),
+        log: vec![String::from("started"), String::from("copied 3 files")],
+        state: State::Running,
+    };
+    let log = job.flush_log();
+    println!("\nflushed {:?}; job log now {:?}", log, job.log);
+
+    let previous = job.finish();
+    println!("{} went from {:?} to {:?}", job.name, previous, job.state);
+}
+
+// Two non-overlapping &mut via split_at_mut, then swap them
+fn swap_elements<T>(v: &mut [T], i: usize, j: usize) {
+    if i == j {
+        return;
+    }
+    let (lo, hi) = (i.min(j), i.max(j));
+    let (left, right) = v.split_at_mut(hi);
+    mem::swap(&mut left[lo], &mut right[0]);
+}
+
+#[derive(Debug)]
+enum State {
+    Running,
+    Finished,
+}
+
+struct Job {
+    name: String,
+    log: Vec<String>,
+    state: State,
+}
+
+impl Job {
+    // `let log = self.log;` fails: can't move out of a borrowed struct.
+    // take() moves the Vec out and leaves an empty one in its place.
+    fn flush_log(&mut self) -> Vec<String> {
+        mem::take(&mut self.log)
+    }
+
+    // State has no Default, so use replace and say what goes in instead
+    fn finish(&mut self) -> State {
+        mem::replace(&mut self.state, State::Finished)
+    }
+}
@@ -0,0 +1,55 @@
+fn main() {
+    // === ONE LOOP, THREE KINDS OF SOURCE ===
+    let sources = vec![
+        Source::Range(3, 7),
+        Source::List(vec![10, 20, 30]),
+        Source::Repeat(9, 4),
+    ];
+
+    for source in &sources {
+        let values: Vec<u32> = source.iter().collect();
+        println!("{:<22} → {:?}", format!("{:?}", source), values);
+    }
+
+    // === ADAPTERS WORK ON THE BOXED ITERATOR TOO ===
+    // Box<dyn Iterator> is itself an Iterator, so map/sum/chain all apply
+    let total: u32 = sources.iter().flat_map(|s| s.iter()).sum();
+    println!("\nsum of everything: {}", total); // 18 + 60 + 36 = 114
+
+    let evens: Vec<u32> = sources[0].iter().filter(|n| n % 2 == 0).collect();
+    println!("evens in {:?}: {:?}", sources[0], evens); // [4, 6]
+
+    // === EMPTY SOURCES JUST YIELD NOTHING ===
+    println!();
+    let empties = [
+        Source::Range(5, 5),
+        Source::Range(8, 2),
+        Source::List(vec![]),
+        Source::Repeat(1, 0),
+    ];
+    for source in &empties {
+        println!("{:?} yields {} items", source, source.iter().count()); // 0
+    }
+}
+
+#[derive(Debug)]
+enum Source {
+    Range(u32, u32),    // start..end, end exclusive
+    List(Vec<u32>),     // exactly these values
+    Repeat(u32, usize), // value, how many times
+}
+
+impl Source {
+    // Each arm builds a *different* iterator type (Range, slice::Iter + copied,
+    // RepeatN). A plain `impl Iterator` return needs one concrete type,
+    // so box them all behind the same trait object.
+    //
+    // The `+ '_` says the iterator may borrow from self — the List arm does.
+    fn iter(&self) -> Box<dyn Iterator<Item = u32> + '_> {
+        match self {
+            Source::Range(start, end) => Box::new(*start..*end),
+            Source::List(values) => Box::new(values.iter().copied()),
+            Source::Repeat(value, times) => Box::new(std::iter::repeat_n(*value, *times)),
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use std::num::ParseIntError;
+
+fn main() {
+    let good = vec!["1", "2", "3"];
+    let bad = vec!["4", "five", "6", "seven"];
+
+    // === WHY A PLAIN CLOSURE CAN'T USE ? ===
+    // `?` returns early from the *closure*, not from main. So the closure
+    // itself must return a Result (or Option) for `?` to have somewhere to go.
+    //
+    //   let doubled: Vec<i32> = good.iter().map(|s| s.parse::<i32>()? * 2).collect();
+    //   error[E0277]: the `?` operator can only be used in a closure that
+    //                 returns `Result` or `Option`
+    //
+    // Here the closure's return type is inferred as i32, so `?` has nowhere
+    // to send the error.
+
+    // === FIX 1: ANNOTATE THE CLOSURE'S RETURN TYPE ===
+    let double = |s: &str| -> Result<i32, ParseIntError> {
+        let n: i32 = s.parse()?;
+        Ok(n * 2)
+    };
+    println!("double(\"21\") = {:?}", double("21")); // Ok(42)
+
+    // === COLLECTING Result<Vec<_>, _> ===
+    // Each element is a Result; collect flips Vec<Result> into Result<Vec>
+    let doubled: Result<Vec<i32>, ParseIntError> = good.iter().map(|s| double(s)).collect();
+    println!("good → {:?}", doubled); // Ok([2, 4, 6])
+
+    // The closure can also be written inline — the annotation does the work
+    let squared: Result<Vec<i32>, ParseIntError> = good
+        .iter()
+        .map(|s| -> Result<i32, ParseIntError> {
+            let n: i32 = s.parse()?;
+            Ok(n * n)
+        })
+        .collect();
+    println!("squared → {:?}", squared); // Ok([1, 4, 9])
+
+    // === THE FIRST ERROR SHORT-CIRCUITS ===
+    // "five" fails, so "6" and "seven" are never parsed
+    let mut seen = Vec::new();
+    let result: Result<Vec<i32>, ParseIntError> = bad
+        .iter()
+        .map(|s| {
+            seen.push(*s);
+            double(s)
+        })
+        .collect();
+    println!("\nbad → {:?}", result.map_err(|e| e.to_string())); // Err("invalid digit found in string")
+    println!("closure ran for: {:?}", seen); // ["4", "five"]
+
+    // === FIX 2: PUT THE LOGIC IN A NAMED FUNCTION ===
+    // Handy when the body grows — `?` is at home in any fn returning Result
+    println!("\nsum of good: {:?}", sum_all(&good)); // Ok(6)
+    println!("sum of bad: {:?}", sum_all(&bad)); // Err(ParseIntError { kind: InvalidDigit })
+
+    // === FIX 3: AN IMMEDIATELY-INVOKED CLOSURE ===
+    // A mini "try block" inside an expression: several ?s, one Result
+    let pair = "8,9";
+    let product = (|| -> Result<i32, ParseIntError> {
+        let (a, b) = pair.split_once(',').unwrap_or((pair, ""));
+        Ok(a.parse::<i32>()? * b.parse::<i32>()?)
+    })();
+    println!("\n{} → product {:?}", pair, product); // Ok(72)
+}
+
+fn sum_all(inputs: &[&str]) -> Result<i32, ParseIntError> {
+    let mut total = 0;
+    for s in inputs {
+        total += s.parse::<i32>()?;
+    }
+    Ok(total)
+}
@@ -0,0 +1,124 @@
+use std::marker::PhantomData;
+
+fn main() {
+    // === THE COMPLETE CHAIN COMPILES ===
+    let account = AccountBuilder::new()
+        .name("ferris")
+        .email("ferris@example.com")
+        .age(8)
+        .build();
+    println!("{:?}", account);
+    println!("{} <{}>, age {:?}", account.name, account.email, account.age);
+
+    // Required setters can come in any order; optional ones anywhere
+    let minimal = AccountBuilder::new()
+        .email("crab@example.com")
+        .name("crab")
+        .build();
+    println!("{:?}", minimal); // age: None
+
+    // === MISSING A REQUIRED FIELD IS A COMPILE ERROR ===
+    // let broken = AccountBuilder::new().email("x@example.com").build();
+    //
+    // error[E0599]: no method named `build` found for struct
+    //               `AccountBuilder<Unset, Set>` in the current scope
+    //   = note: the method was found for
+    //           - `AccountBuilder<Set, Set>`
+    //
+    // A runtime builder would return Err("name is required") here — the
+    // typestate version never lets the mistake reach runtime.
+
+    // === SETTING A FIELD TWICE ISN'T POSSIBLE EITHER ===
+    // AccountBuilder::new().name("a").name("b")
+    // error[E0599]: no method named `name` found for struct `AccountBuilder<Set, Unset>`
+
+    // === THE TYPE CHANGES AT EACH STEP ===
+    let step = AccountBuilder::new();
+    println!("\n{}", type_name_of(&step)); // AccountBuilder<Unset, Unset>
+    let step = step.name("ferris");
+    println!("{}", type_name_of(&step)); // AccountBuilder<Set, Unset>
+    let step = step.email("ferris@example.com");
+    println!("{}", type_name_of(&step)); // AccountBuilder<Set, Set>
+    println!("{:?}", step.build());
+}
+
+#[derive(Debug)]
+struct Account {
+    name: String,
+    email: String,
+    age: Option<u32>,
+}
+
+// Marker types: zero-sized, never constructed, only used as type parameters
+struct Unset;
+struct Set;
+
+// N tracks whether the name is set, E whether the email is. PhantomData
+// "uses" the parameters so the compiler accepts them — it takes no space.
+struct AccountBuilder<N, E> {
+    name: Option<String>,
+    email: Option<String>,
+    age: Option<u32>,
+    state: PhantomData<(N, E)>,
+}
+
+impl AccountBuilder<Unset, Unset> {
+    fn new() -> Self {
+        AccountBuilder {
+            name: None,
+            email: None,
+            age: None,
+            state: PhantomData,
+        }
+    }
+}
+
+// Only available while the name is Unset; returns a builder with N = Set
+impl<E> AccountBuilder<Unset, E> {
+    fn name(self, name: &str) -> AccountBuilder<Set, E> {
+        AccountBuilder {
+            name: Some(name.to_string()),
+            email: self.email,
+            age: self.age,
+            state: PhantomData,
+        }
+    }
+}
+
+impl<N> AccountBuilder<N, Unset> {
+    fn email(self, email: &str) -> AccountBuilder<N, Set> {
+        AccountBuilder {
+            name: self.name,
+            email: Some(email.to_string()),
+            age: self.age,
+            state: PhantomData,
+        }
+    }
+}
+
+// Optional fields don't change the state, so they work in every state
+impl<N, E> AccountBuilder<N, E> {
+    fn age(mut self, age: u32) -> Self {
+        self.age = Some(age);
+        self
+    }
+}
+
+// build() exists only once both required fields are Set. The unwraps
+// can't fail: the type proves the setters ran.
+impl AccountBuilder<Set, Set> {
+    fn build(self) -> Account {
+        Account {
+            name: self.name.unwrap(),
+            email: self.email.unwrap(),
+            age: self.age,
+        }
+    }
+}
+
+// type_name gives full paths like "ch103_typestate_builder::Set";
+// drop this file's module prefix for readable output
+fn type_name_of<T>(_: &T) -> String {
+    let prefix = concat!(module_path!(), "::");
+    std::any::type_name::<T>().replace(prefix, "")
+}
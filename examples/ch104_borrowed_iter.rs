@@ -0,0 +1,72 @@
+fn main() {
+    let mut playlist = Playlist::new();
+    playlist.add("Bohemian Rhapsody");
+    playlist.add("Blue in Green");
+    playlist.add("Clair de Lune");
+
+    // === ITERATE WITHOUT SEEING THE Vec ===
+    // Callers only know "something iterable of &String" — the field stays
+    // private, so we could switch to a VecDeque later without breaking them
+    for (i, song) in playlist.iter().enumerate() {
+        println!("{}. {}", i + 1, song);
+    }
+
+    // It's a normal iterator, so adapters chain on as usual
+    let longest = playlist.iter().max_by_key(|s| s.len());
+    println!("\nlongest title: {:?}", longest);
+
+    // === DERIVED VALUES, STILL LAZY ===
+    let shouty: Vec<String> = playlist.titles_uppercase().collect();
+    println!("{:?}", shouty);
+
+    let starts_with_b = playlist
+        .titles_uppercase()
+        .filter(|t| t.starts_with('B'))
+        .count();
+    println!("titles starting with B: {}", starts_with_b); // 2
+
+    // === THE ITERATOR BORROWS THE PLAYLIST ===
+    // While an iterator is alive, the playlist can't be changed or dropped:
+    //
+    //   let upper = playlist.titles_uppercase();
+    //   playlist.add("Yesterday");
+    //   // error[E0502]: cannot borrow `playlist` as mutable because it is
+    //   //               also borrowed as immutable
+    //   upper.count();
+    //
+    // Once the iterator is used up, the borrow ends and we can mutate again
+    playlist.add("Yesterday");
+    println!("now {} songs", playlist.iter().count()); // 4
+}
+
+struct Playlist {
+    songs: Vec<String>, // private — not reachable from outside this impl
+}
+
+impl Playlist {
+    fn new() -> Self {
+        Playlist { songs: Vec::new() }
+    }
+
+    fn add(&mut self, title: &str) {
+        self.songs.push(title.to_string());
+    }
+
+    // Just hand out the Vec's own iterator, hidden behind impl Iterator
+    fn iter(&self) -> impl Iterator<Item = &String> + '_ {
+        self.songs.iter()
+    }
+
+    // `+ '_` says "this iterator borrows from self". The Item (String) is
+    // owned, so nothing in the signature mentions the borrow otherwise.
+    //
+    // In edition 2021, leaving off `+ '_` here is an error:
+    //   error[E0700]: hidden type for `impl Iterator<Item = String>`
+    //                 captures lifetime that does not appear in bounds
+    //
+    // Edition 2024 (this crate) captures the borrow automatically, so the
+    // annotation is optional — but it still documents the tie to self.
+    fn titles_uppercase(&self) -> impl Iterator<Item = String> + '_ {
+        self.songs.iter().map(|s| s.to_uppercase())
+    }
+}
@@ -0,0 +1,77 @@
+use std::rc::Rc;
+
+fn main() {
+    // === LOCALS: REVERSE ORDER OF DECLARATION ===
+    println!("--- locals ---");
+    {
+        let _first = Guard("first");
+        let _second = Guard("second");
+        println!("end of scope");
+    } // second, then first — later values may depend on earlier ones
+
+    // === Vec: FRONT TO BACK ===
+    // Elements drop in index order, after the Vec itself starts dropping
+    println!("\n--- Vec ---");
+    {
+        let mut guards = Vec::new();
+        for name in ["v[0]", "v[1]", "v[2]"] {
+            guards.push(Guard(name));
+        }
+        println!("end of scope with {} guards", guards.len());
+    } // v[0], v[1], v[2]
+
+    // === Box: THE CONTENTS DROP WITH THE BOX ===
+    println!("\n--- Box ---");
+    {
+        let boxed = Box::new(Guard("boxed"));
+        println!("boxed guard is {}", boxed.0);
+        drop(boxed); // drop early: runs Guard's drop, then frees the heap slot
+        println!("after drop(boxed)");
+    }
+
+    // === STRUCT FIELDS: DECLARATION ORDER ===
+    println!("\n--- struct fields ---");
+    {
+        let pair = Pair {
+            a: Guard("pair.a"),
+            b: Guard("pair.b"),
+        };
+        println!("holding {} and {}", pair.a.0, pair.b.0);
+    } // pair.a, then pair.b
+
+    // === Rc: ONLY WHEN THE LAST CLONE GOES ===
+    println!("\n--- Rc ---");
+    let shared = Rc::new(Guard("shared"));
+    let clone = Rc::clone(&shared);
+    println!("strong count: {}", Rc::strong_count(&shared)); // 2
+
+    drop(shared);
+    // Nothing printed — one owner is left, so Guard::drop hasn't run
+    println!(
+        "dropped one handle, strong count: {}",
+        Rc::strong_count(&clone)
+    ); // 1
+
+    {
+        let kept_alive = Rc::clone(&clone);
+        drop(clone);
+        println!("dropped another, still alive via {}", kept_alive.0);
+    } // kept_alive was the last owner → "drop shared" prints here
+
+    println!("\n--- end of main ---");
+    let _last = Guard("main local");
+}
+
+// Prints when it's dropped, so the timing is visible
+struct Guard(&'static str);
+
+impl Drop for Guard {
+    fn drop(&mut self) {
+        println!("  drop {}", self.0);
+    }
+}
+
+struct Pair {
+    a: Guard,
+    b: Guard,
+}
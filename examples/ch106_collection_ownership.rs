@@ -0,0 +1,52 @@
+fn main() {
+    // === iter() — BORROWS, YIELDS &T ===
+    let names = vec![String::from("ada"), String::from("bob"), String::from("cy")];
+    let lengths: Vec<usize> = names.iter().map(|n| n.len()).collect();
+    println!("lengths {:?}, names still usable: {:?}", lengths, names);
+
+    // === into_iter() — CONSUMES, YIELDS T ===
+    // Each String is moved out, so we can keep them without cloning
+    let shouted: Vec<String> = names
+        .into_iter()
+        .map(|mut n| {
+            n.make_ascii_uppercase(); // we own n, so mutating it is fine
+            n
+        })
+        .collect();
+    println!("shouted: {:?}", shouted);
+    // println!("{:?}", names); // error[E0382]: borrow of moved value: `names`
+    // (`for n in names` is the same as `names.into_iter()` — it moves too)
+
+    // === drain(range) — MOVES ITEMS OUT, KEEPS THE Vec ===
+    // Take the first half into a new Vec; the rest stays behind
+    let mut queue: Vec<String> = (1..=6).map(|i| format!("job{}", i)).collect();
+    let half = queue.len() / 2;
+    let batch: Vec<String> = queue.drain(..half).collect();
+    println!("\nbatch: {:?}", batch); // job1..job3, owned
+    println!("left:  {:?}", queue); // job4..job6
+
+    // The Vec is still ours after drain — keep using it
+    queue.push(String::from("job7"));
+    // Draining a middle range closes the gap, like a bulk remove
+    let middle: Vec<String> = queue.drain(1..3).collect();
+    println!("took {:?} from the middle, left {:?}", middle, queue); // [job5, job6], [job4, job7]
+
+    // === retain — FILTER IN PLACE ===
+    // Nothing is moved out; rejected items are simply dropped
+    let mut numbers: Vec<i32> = (1..=10).collect();
+    numbers.retain(|&n| n % 2 != 0);
+    println!("\nodds only: {:?}", numbers); // [1, 3, 5, 7, 9]
+
+    // === split_off — CUT THE TAIL INTO A NEW Vec ===
+    // `numbers` keeps [0, at), the returned Vec owns [at, len)
+    let tail = numbers.split_off(3);
+    println!("head {:?}, tail {:?}", numbers, tail); // [1, 3, 5] [7, 9]
+
+    // === QUICK REFERENCE ===
+    // iter()       &T         collection untouched
+    // iter_mut()   &mut T     collection modified in place
+    // into_iter()  T          collection consumed
+    // drain(r)     T          items in r removed, collection kept
+    // retain(f)    —          rejected items dropped in place
+    // split_off(i) Vec<T>     tail moved into a new Vec
+}
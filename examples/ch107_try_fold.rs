@@ -0,0 +1,68 @@
+use std::cell::Cell;
+use std::num::ParseIntError;
+
+fn main() {
+    // === fold CAN'T STOP EARLY ===
+    // It visits every item no matter what; an error just gets carried along
+    let inputs = ["10", "x", "30", "40"];
+    let visited = Cell::new(0);
+    // (clippy flags this pattern as manual_try_fold — which is the point)
+    #[allow(clippy::manual_try_fold)]
+    let folded: Result<i32, ParseIntError> = inputs.iter().fold(Ok(0), |acc, s| {
+        visited.update(|n| n + 1);
+        let n: i32 = s.parse()?; // ? here returns from the closure, not from fold
+        acc.map(|total| total + n)
+    });
+    println!(
+        "fold: is_err = {} after visiting {} items",
+        folded.is_err(),
+        visited.get()
+    ); // true, 4
+
+    // === try_fold STOPS AT THE FIRST ERROR ===
+    // The closure returns Result; the first Err ends the whole fold
+    let parsed = Cell::new(0);
+    let sum_strs = |items: &[&str]| -> Result<i32, ParseIntError> {
+        items.iter().try_fold(0, |total, s| {
+            parsed.update(|n| n + 1);
+            Ok(total + s.parse::<i32>()?)
+        })
+    };
+
+    println!("\nall valid: {:?}", sum_strs(&["10", "20", "30"])); // Ok(60)
+    parsed.set(0);
+    let result = sum_strs(&inputs);
+    println!("mid error: {:?}", result.map_err(|e| e.to_string())); // Err("invalid digit found in string")
+    println!("parsed only {} of {} items", parsed.get(), inputs.len()); // 2 of 4
+
+    // === try_fold WITH Option ===
+    // checked_add returns None on overflow, which stops the fold
+    let fits = [100u8, 50, 50]
+        .iter()
+        .try_fold(0u8, |acc, &x| acc.checked_add(x));
+    let overflows = [100u8, 100, 100]
+        .iter()
+        .try_fold(0u8, |acc, &x| acc.checked_add(x));
+    println!("\nu8 sums: {:?} and {:?}", fits, overflows); // Some(200) and None
+
+    // === try_for_each — VALIDATE UNTIL SOMETHING'S WRONG ===
+    // Like for_each, but the closure can bail out with Err
+    let usernames = ["alice", "bob", "c@rl", "dave"];
+    let checked = Cell::new(0);
+    let outcome = usernames.iter().try_for_each(|name| {
+        checked.update(|n| n + 1);
+        validate(name)
+    });
+    println!("\nvalidation: {:?}", outcome); // Err("'c@rl' contains '@'")
+    println!("checked {} names, never looked at 'dave'", checked.get()); // 3
+
+    let clean = ["alice", "bob"].iter().try_for_each(|name| validate(name));
+    println!("clean list: {:?}", clean); // Ok(())
+}
+
+fn validate(name: &str) -> Result<(), String> {
+    match name.chars().find(|c| !c.is_ascii_alphanumeric()) {
+        Some(bad) => Err(format!("'{}' contains '{}'", name, bad)),
+        None => Ok(()),
+    }
+}
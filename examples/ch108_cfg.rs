@@ -0,0 +1,87 @@
+fn main() {
+    // Try both:
+    //   cargo run --example ch108_cfg
+    //   cargo run --example ch108_cfg --release
+
+    // === cfg!(...) — A COMPILE-TIME FACT AS A RUNTIME bool ===
+    // Both branches are compiled and type-checked; one is just never taken
+    let mode = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+    println!("build mode: {}", mode);
+    println!("running on: {}", platform());
+    println!("64-bit pointers: {}", cfg!(target_pointer_width = "64"));
+
+    // === SAME RESULT, EXTRA LOGGING IN DEBUG ===
+    let total = checksum(&[3, 1, 4, 1, 5, 9]);
+    println!("checksum: {}", total); // 23 in both modes
+
+    // === debug_assert! — CHECKED IN DEBUG, REMOVED IN RELEASE ===
+    // Good for expensive sanity checks you don't want to pay for in production
+    debug_assert!(total < 1000, "checksum unexpectedly large");
+    println!("invariants ok");
+}
+
+// === #[cfg] ON ITEMS — THE CODE ISN'T COMPILED AT ALL WHEN FALSE ===
+// Each variant has the same signature, so callers don't care which one exists
+
+#[cfg(target_os = "linux")]
+fn platform() -> &'static str {
+    "Linux"
+}
+
+#[cfg(target_os = "macos")]
+fn platform() -> &'static str {
+    "macOS"
+}
+
+#[cfg(target_os = "windows")]
+fn platform() -> &'static str {
+    "Windows"
+}
+
+// Everything else — without this, other platforms wouldn't compile
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+fn platform() -> &'static str {
+    "something else"
+}
+
+fn checksum(data: &[u32]) -> u32 {
+    let sum = data.iter().sum();
+    // Only this statement changes between builds
+    #[cfg(debug_assertions)]
+    debug_log(data, sum);
+    sum
+}
+
+// Only exists in debug builds; nothing in release may call it
+#[cfg(debug_assertions)]
+fn debug_log(data: &[u32], sum: u32) {
+    println!(
+        "  [debug] checksum of {} items {:?} = {}",
+        data.len(),
+        data,
+        sum
+    );
+}
+
+// === #[cfg(test)] — ONLY BUILT BY `cargo test` ===
+// Run with: cargo test --example ch108_cfg
+// and:      cargo test --example ch108_cfg --release
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn checksum_same_in_every_build() {
+        assert_eq!(checksum(&[3, 1, 4, 1, 5, 9]), 23);
+        assert_eq!(checksum(&[]), 0);
+    }
+
+    #[test]
+    fn platform_is_known_string() {
+        assert!(!platform().is_empty());
+    }
+}
@@ -0,0 +1,82 @@
+fn main() {
+    // === Box<dyn Fn()> MEANS Box<dyn Fn() + 'static> ===
+    // With no lifetime written, a boxed trait object defaults to 'static:
+    // it may not borrow anything that could go away. Closures that own
+    // their data (via `move`) qualify.
+    let greeting = String::from("hello");
+    let owned: Box<dyn Fn() -> usize> = Box::new(move || greeting.len());
+    println!("owned closure: {}", owned()); // 5
+
+    let mut tasks: Vec<Box<dyn Fn() -> String>> = Vec::new();
+    register(&mut tasks, 3);
+    register(&mut tasks, 7);
+    for task in &tasks {
+        println!("{}", task());
+    }
+
+    // === A CLOSURE THAT BORROWS ISN'T 'static ===
+    // register() can't accept a closure that borrows its argument:
+    //
+    //   fn register_name(tasks: &mut Vec<Box<dyn Fn() -> String>>, name: &str) {
+    //       tasks.push(Box::new(move || name.to_uppercase()));
+    //   }
+    //
+    //   error: lifetime may not live long enough
+    //     coercion requires that `'1` must outlive `'static`
+    //
+    // The closure holds `name: &str`, and the Vec's boxes are 'static —
+    // they could outlive whatever `name` points into.
+    //
+    // A shorter bound fixes it. `'_` lets the compiler pick the borrow's
+    // lifetime (inside a function body it's inferred even if omitted, but
+    // in signatures and struct fields the 'static default applies).
+    let numbers = vec![1, 2, 3, 4];
+    let total: Box<dyn Fn() -> i32 + '_> = Box::new(|| numbers.iter().sum());
+    println!("\nborrowing closure: {}", total()); // 10
+
+    // === STORING BORROWING TRAIT OBJECTS IN A SCOPE ===
+    let threshold = 2;
+    {
+        let checks: Vec<Box<dyn Fn(i32) -> bool + '_>> = vec![
+            Box::new(|n| n > threshold),
+            Box::new(|n| numbers.contains(&n)),
+        ];
+        for n in [1, 3, 9] {
+            let results: Vec<bool> = checks.iter().map(|check| check(n)).collect();
+            println!("{} → {:?}", n, results);
+        }
+    } // checks dropped here, releasing the borrows
+    println!("numbers still ours: {:?}", numbers);
+
+    // === RETURNING A BORROWING TRAIT OBJECT ===
+    let prices = [4, 8, 15];
+    let sum_prices = summer(&prices);
+    println!("\nsum via returned closure: {}", sum_prices()); // 27
+
+    let counter = Counter { step: 5 };
+    let stepper = counter.stepper(10);
+    println!("stepper: {}", stepper()); // 15
+}
+
+// Goes into a Vec<Box<dyn Fn() -> String>> — the 'static default.
+// `move` copies `id` into the closure, so it borrows nothing.
+fn register(tasks: &mut Vec<Box<dyn Fn() -> String>>, id: u32) {
+    tasks.push(Box::new(move || format!("task #{} ran", id)));
+}
+
+// The returned closure borrows `values`, so it can't outlive it.
+// `+ '_` ties the trait object to the input slice's lifetime.
+fn summer(values: &[i32]) -> Box<dyn Fn() -> i32 + '_> {
+    Box::new(move || values.iter().sum())
+}
+
+struct Counter {
+    step: i32,
+}
+
+impl Counter {
+    // Here `'_` is the lifetime of &self
+    fn stepper(&self, start: i32) -> Box<dyn Fn() -> i32 + '_> {
+        Box::new(move || start + self.step)
+    }
+}
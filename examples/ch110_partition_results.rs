@@ -0,0 +1,52 @@
+fn main() {
+    // === collect::<Result<Vec<_>, _>>() STOPS AT THE FIRST ERROR ===
+    let inputs = ["12", "seven", "40", "", "-3", "9x"];
+    let all_or_nothing: Result<Vec<i32>, _> = inputs.iter().map(|s| s.parse::<i32>()).collect();
+    println!(
+        "all-or-nothing: {:?}",
+        all_or_nothing.map_err(|e| e.to_string())
+    ); // first error only
+
+    // === KEEP GOING: SPLIT SUCCESSES FROM FAILURES ===
+    let (numbers, errors) = parse_all(&inputs);
+    println!(
+        "\nparsed {} of {}: {:?}",
+        numbers.len(),
+        inputs.len(),
+        numbers
+    ); // [12, 40, -3]
+    println!("{} failed:", errors.len());
+    for e in &errors {
+        println!("  {}", e);
+    }
+
+    // === EDGE CASES ===
+    let (ok, bad) = parse_all(&["1", "2", "3"]);
+    println!("\nall valid:   {:?} / errors {:?}", ok, bad); // [1, 2, 3] / []
+    let (ok, bad) = parse_all(&["a", "b"]);
+    println!("all invalid: {:?} / {} errors", ok, bad.len()); // [] / 2
+
+    // === THE TWO-PASS ALTERNATIVE ===
+    // Iterator::partition splits by a predicate, but leaves everything
+    // wrapped in Result — you still have to unwrap each side afterward
+    let (oks, errs): (Vec<_>, Vec<_>) = inputs
+        .iter()
+        .map(|s| s.parse::<i32>())
+        .partition(|r| r.is_ok());
+    let oks: Vec<i32> = oks.into_iter().map(Result::unwrap).collect();
+    println!("\npartition + unwrap: {:?}, {} errors", oks, errs.len());
+}
+
+// A hand-rolled "partition_map": one pass, each item goes into exactly
+// one of the two Vecs, already unwrapped
+fn parse_all(inputs: &[&str]) -> (Vec<i32>, Vec<String>) {
+    inputs
+        .iter()
+        .fold((Vec::new(), Vec::new()), |(mut ok, mut bad), s| {
+            match s.parse::<i32>() {
+                Ok(n) => ok.push(n),
+                Err(e) => bad.push(format!("{:?}: {}", s, e)),
+            }
+            (ok, bad)
+        })
+}
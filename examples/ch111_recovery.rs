@@ -0,0 +1,107 @@
+use std::num::ParseIntError;
+
+fn main() {
+    // === unwrap_or_default — FALL BACK TO THE TYPE'S Default ===
+    // ch08 showed unwrap_or(0); this picks the "zero" for you
+    let n: i32 = "oops".parse().unwrap_or_default();
+    let f: f64 = "".parse().unwrap_or_default();
+    let flag: bool = "maybe".parse().unwrap_or_default();
+    println!("defaults after failed parses: {} {} {}", n, f, flag); // 0 0 false
+
+    let words: Vec<String> = read_list("").unwrap_or_default();
+    println!("empty list on error: {:?}", words); // []
+
+    // === or_else — TRY SOMETHING ELSE ON ERROR ===
+    // Unlike unwrap_or_else, the fallback can fail too, so the result is
+    // still a Result
+    for input in ["8080", "0x1F90", "http"] {
+        let port = parse_decimal(input).or_else(|_| parse_hex(input));
+        println!("{:>7} → {:?}", input, port.map_err(|e| e.to_string()));
+    }
+
+    // === RECOVER AND LOG vs PROPAGATE ===
+    println!();
+    for text in ["timeout=5", "timeout: 7", "timeout=abc"] {
+        let config = load_config_or_default(text);
+        println!(
+            "{:>12} → timeout {}s, retries {}",
+            text, config.timeout, config.retries
+        );
+    }
+    match load_config("timeout=abc") {
+        Ok(c) => println!("loaded: {:?}", c),
+        Err(e) => println!("propagated instead: {}", e),
+    }
+
+    // === Option::get_or_insert_with — LAZY INIT ON FIRST USE ===
+    println!();
+    let mut cache: Option<Vec<u32>> = None;
+    for round in 1..=3 {
+        let primes = cache.get_or_insert_with(|| {
+            println!("  (computing primes, round {})", round); // printed only once
+            vec![2, 3, 5, 7, 11]
+        });
+        println!("round {}: {} primes", round, primes.len());
+    }
+}
+
+fn read_list(text: &str) -> Result<Vec<String>, String> {
+    if text.is_empty() {
+        return Err(String::from("nothing to read"));
+    }
+    Ok(text.split(',').map(String::from).collect())
+}
+
+fn parse_decimal(s: &str) -> Result<u16, ParseIntError> {
+    s.parse()
+}
+
+fn parse_hex(s: &str) -> Result<u16, ParseIntError> {
+    u16::from_str_radix(s.trim_start_matches("0x"), 16)
+}
+
+#[derive(Debug, Default)]
+struct Config {
+    timeout: u32,
+    retries: u8,
+}
+
+// Propagating: the caller decides what an error means
+fn load_config(text: &str) -> Result<Config, String> {
+    let value = text
+        .strip_prefix("timeout=")
+        .ok_or("expected 'timeout=<n>'")?;
+    let timeout = value
+        .parse()
+        .map_err(|e| format!("bad timeout {:?}: {}", value, e))?;
+    Ok(Config {
+        timeout,
+        retries: 3,
+    })
+}
+
+// The old "timeout: <n>" format, still accepted as a fallback
+fn load_legacy_config(text: &str) -> Result<Config, String> {
+    let value = text
+        .strip_prefix("timeout:")
+        .ok_or("not legacy format either")?;
+    let timeout = value
+        .trim()
+        .parse()
+        .map_err(|e| format!("bad legacy timeout: {}", e))?;
+    Ok(Config {
+        timeout,
+        retries: 1,
+    })
+}
+
+// Recovering: try the current format, then the legacy one, and if both
+// fail log why and carry on with defaults
+fn load_config_or_default(text: &str) -> Config {
+    load_config(text)
+        .or_else(|_| load_legacy_config(text))
+        .unwrap_or_else(|e| {
+            println!("  warning: {} — using defaults", e);
+            Config::default()
+        })
+}
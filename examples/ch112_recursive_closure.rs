@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+fn main() {
+    // === WHY A CLOSURE CAN'T CALL ITSELF ===
+    //
+    //   let fact = |n: u64| if n == 0 { 1 } else { n * fact(n - 1) };
+    //
+    //   error[E0425]: cannot find function `fact` in this scope
+    //
+    // `fact` doesn't exist until the `let` finishes, so the closure body
+    // can't see it. And even if it could, the closure's type would have to
+    // contain itself (it captures `fact`, whose type is the closure...),
+    // which no finite type can do.
+
+    // === WORKAROUND 1: A NAMED fn ===
+    // fn items are visible throughout their scope, including inside
+    // themselves. Simplest option when nothing needs capturing.
+    println!("fn:          10! = {}", fact_fn(10)); // 3628800
+
+    // fns can even be nested inside the function that uses them
+    fn fib(n: u64) -> u64 {
+        if n < 2 { n } else { fib(n - 1) + fib(n - 2) }
+    }
+    println!("nested fn:   fib(20) = {}", fib(20)); // 6765
+
+    // === WORKAROUND 2: PASS THE FUNCTION TO ITSELF ===
+    // The closure takes "the thing to call for recursion" as a parameter.
+    // A &dyn Fn breaks the infinite type: the parameter's type is a
+    // trait object, not the closure's own type.
+    let fact = |n: u64, recurse: &dyn Fn(u64) -> u64| -> u64 {
+        if n == 0 { 1 } else { n * recurse(n - 1) }
+    };
+    println!("self-passing: 10! = {}", fix(&fact, 10));
+
+    // === WORKAROUND 3: A STRUCT HOLDING A Box<dyn Fn> ===
+    // The struct gives the closure a name it can reach through `&self`,
+    // and lets it carry captured state — here, a multiplier.
+    let scale = 2;
+    let doubled_fact = Recursive::new(
+        move |me: &Recursive<'_>, n: u64| {
+            if n == 0 { scale } else { n * me.call(n - 1) }
+        },
+    );
+    println!("struct:      2 × 10! = {}", doubled_fact.call(10)); // 7257600
+
+    // === WHEN YOU NEED MUTABLE STATE: AN EXPLICIT CONTEXT ===
+    // Memoised fib — the cache is passed in rather than captured, so the
+    // borrow checker only ever sees one &mut at a time
+    let mut memo = HashMap::new();
+    println!("memo fn:     fib(80) = {}", fib_memo(80, &mut memo));
+}
+
+fn fact_fn(n: u64) -> u64 {
+    if n == 0 { 1 } else { n * fact_fn(n - 1) }
+}
+
+// A function with its recursive call left as a parameter
+type OpenFn = dyn Fn(u64, &dyn Fn(u64) -> u64) -> u64;
+
+// Ties the knot for workaround 2: calls `f`, handing it a closure that
+// calls `fix` again for the recursive step
+fn fix(f: &OpenFn, n: u64) -> u64 {
+    f(n, &|m| fix(f, m))
+}
+
+// The struct in workaround 3. The boxed closure receives the struct
+// itself, so it can call back in through `me.call(...)`.
+type Body<'a> = Box<dyn Fn(&Recursive<'a>, u64) -> u64 + 'a>;
+
+struct Recursive<'a> {
+    body: Body<'a>,
+}
+
+impl<'a> Recursive<'a> {
+    fn new(body: impl Fn(&Recursive<'a>, u64) -> u64 + 'a) -> Self {
+        Recursive {
+            body: Box::new(body),
+        }
+    }
+
+    fn call(&self, n: u64) -> u64 {
+        (self.body)(self, n)
+    }
+}
+
+fn fib_memo(n: u64, memo: &mut HashMap<u64, u64>) -> u64 {
+    if n < 2 {
+        return n;
+    }
+    if let Some(&v) = memo.get(&n) {
+        return v;
+    }
+    let v = fib_memo(n - 1, memo) + fib_memo(n - 2, memo);
+    memo.insert(n, v);
+    v
+}
@@ -0,0 +1,101 @@
+use rust_journey::shapes::{Circle, Rectangle, Shape};
+use std::hint::black_box;
+use std::time::Instant;
+
+// Timings only mean something with optimisations on:
+//   cargo run --example ch113_enum_vs_dyn --release
+
+const COUNT: usize = 1_000_000;
+const ROUNDS: usize = 20;
+
+fn main() {
+    // === SAME SHAPES, TWO REPRESENTATIONS ===
+    let enums: Vec<ShapeKind> = (0..COUNT)
+        .map(|i| {
+            let x = (i % 10) as f64 + 1.0;
+            if i % 2 == 0 {
+                ShapeKind::Circle { radius: x }
+            } else {
+                ShapeKind::Rectangle {
+                    width: x,
+                    height: 2.0,
+                }
+            }
+        })
+        .collect();
+
+    let boxed: Vec<Box<dyn Shape>> = (0..COUNT)
+        .map(|i| {
+            let x = (i % 10) as f64 + 1.0;
+            if i % 2 == 0 {
+                Box::new(Circle { radius: x }) as Box<dyn Shape>
+            } else {
+                Box::new(Rectangle {
+                    width: x,
+                    height: 2.0,
+                })
+            }
+        })
+        .collect();
+
+    // === TIME THE SAME LOOP OVER EACH ===
+    let start = Instant::now();
+    let mut enum_total = 0.0;
+    for _ in 0..ROUNDS {
+        enum_total += black_box(&enums).iter().map(|s| s.area()).sum::<f64>();
+    }
+    let enum_time = start.elapsed();
+
+    let start = Instant::now();
+    let mut dyn_total = 0.0;
+    for _ in 0..ROUNDS {
+        dyn_total += black_box(&boxed).iter().map(|s| s.area()).sum::<f64>();
+    }
+    let dyn_time = start.elapsed();
+
+    println!("{} shapes × {} rounds", COUNT, ROUNDS);
+    println!(
+        "{:<15} {:>9.2?}  (total area {:.0})",
+        "enum + match", enum_time, enum_total
+    );
+    println!(
+        "{:<15} {:>9.2?}  (total area {:.0})",
+        "Box<dyn Shape>", dyn_time, dyn_total
+    );
+    println!(
+        "dyn / enum: {:.2}×",
+        dyn_time.as_secs_f64() / enum_time.as_secs_f64()
+    );
+
+    // === WHY THE ENUM IS USUALLY FASTER ===
+    // - enum: area() is a plain function; the match is a jump on the tag,
+    //   and the compiler can inline and vectorise the whole loop
+    // - dyn: each call goes through a vtable pointer, which blocks inlining
+    // - layout: the enums sit inline in one contiguous Vec; each Box is a
+    //   separate heap allocation, so the loop chases pointers
+    // Exact numbers vary by machine — the gap, not the values, is the point.
+
+    // === THE DESIGN TRADE-OFF ===
+    // Enum — CLOSED: every variant is listed right here. Adding a Hexagon
+    //   means editing ShapeKind and every match on it; code in another
+    //   crate can't add one. In exchange, the compiler checks every match
+    //   handles every variant.
+    // Trait object — OPEN: anyone can `impl Shape for Hexagon` in their own
+    //   crate and put it in the same Vec<Box<dyn Shape>>, no edits needed
+    //   here. Adding a new *method*, though, touches every impl.
+}
+
+#[derive(Debug, Clone, Copy)]
+enum ShapeKind {
+    Circle { radius: f64 },
+    Rectangle { width: f64, height: f64 },
+}
+
+impl ShapeKind {
+    fn area(&self) -> f64 {
+        match *self {
+            ShapeKind::Circle { radius } => std::f64::consts::PI * radius * radius,
+            ShapeKind::Rectangle { width, height } => width * height,
+        }
+    }
+}
@@ -0,0 +1,85 @@
+// ch01 showed `const MAX_POINTS: u32 = 100_000;` — a value fixed at
+// compile time. A `const fn` extends that: a function the compiler can
+// run *during compilation* when it's used in a const context.
+
+const fn square(x: u32) -> u32 {
+    x * x
+}
+
+// Loops, if/match, let, and calls to other const fns are all allowed
+const fn factorial(n: u64) -> u64 {
+    let mut acc = 1;
+    let mut i = 2;
+    while i <= n {
+        acc *= i;
+        i += 1;
+    }
+    acc
+}
+
+// === CONSTS COMPUTED AT COMPILE TIME ===
+// These are evaluated by the compiler; the binary just contains the results
+const GRID: usize = square(4) as usize; // 16
+const FACT_10: u64 = factorial(10);
+const SECONDS_PER_WEEK: u32 = 60 * 60 * 24 * 7;
+
+// Overflow in a const is a *compile error*, not a runtime panic:
+// const TOO_BIG: u32 = square(70_000);
+// error[E0080]: attempt to compute `70000_u32 * 70000_u32`, which would overflow
+
+// === TYPED UNITS WITH const ARITHMETIC ===
+// A newtype keeps millimetres from being mixed up with plain numbers,
+// and const fns let us build and combine them in consts
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Millimeters(u32);
+
+impl Millimeters {
+    const fn from_cm(cm: u32) -> Self {
+        Millimeters(cm * 10)
+    }
+
+    const fn from_m(m: u32) -> Self {
+        Millimeters(m * 1000)
+    }
+
+    // Operator traits (impl Add) can't be called in const contexts yet,
+    // so combine with a const method instead
+    const fn plus(self, other: Millimeters) -> Millimeters {
+        Millimeters(self.0 + other.0)
+    }
+}
+
+const DOOR_HEIGHT: Millimeters = Millimeters::from_m(2).plus(Millimeters::from_cm(10));
+
+fn main() {
+    // === A const fn RESULT AS AN ARRAY LENGTH ===
+    // Array lengths must be known at compile time — a regular fn won't do
+    let board = [0u8; square(4) as usize];
+    println!("board has {} cells", board.len()); // 16
+
+    let cells: [char; GRID] = ['.'; GRID];
+    println!("grid row: {}", cells.iter().take(4).collect::<String>());
+
+    // let bad = [0u8; runtime_square(4) as usize];
+    // error[E0015]: cannot call non-const function `runtime_square` in constants
+
+    // === THE SAME const fn ALSO WORKS AT RUNTIME ===
+    // With a runtime argument, it's just an ordinary call
+    let n: u32 = std::env::args().count() as u32 + 2; // not known until the program runs
+    println!("\nsquare({}) at runtime = {}", n, square(n));
+    println!("runtime_square({}) = {}", n, runtime_square(n));
+
+    // === THE PRECOMPUTED VALUES ===
+    println!("\n10! = {}", FACT_10); // 3628800
+    println!("seconds per week = {}", SECONDS_PER_WEEK); // 604800
+    println!("door height = {:?} ({} mm)", DOOR_HEIGHT, DOOR_HEIGHT.0); // 2100 mm
+
+    // A const block forces compile-time evaluation inside an expression
+    let lookup = const { [square(1), square(2), square(3), square(4)] };
+    println!("squares table: {:?}", lookup);
+}
+
+// An ordinary fn: same body, but only callable at runtime
+fn runtime_square(x: u32) -> u32 {
+    x * x
+}
@@ -0,0 +1,48 @@
+use std::cell::Cell;
+
+fn main() {
+    let readings = [3, 8, -1, 12, 7, -4, 10];
+
+    // === THE PIPELINE WITHOUT inspect ===
+    let plain: Vec<i32> = readings
+        .iter()
+        .map(|&x| x * 10)
+        .filter(|&x| x > 50)
+        .collect();
+    println!("result: {:?}", plain); // [80, 120, 70, 100]
+
+    // === THE SAME PIPELINE, WATCHED ===
+    // inspect hands each item to a closure by reference and passes it on
+    // unchanged. Logs go to stderr (eprintln!) so they don't mix with the
+    // program's real output when piped.
+    let watched: Vec<i32> = readings
+        .iter()
+        .map(|&x| x * 10)
+        .inspect(|x| eprintln!("  after map:    {}", x))
+        .filter(|&x| x > 50)
+        .inspect(|x| eprintln!("  after filter: {}", x))
+        .collect();
+    println!("result: {:?}", watched);
+    println!("identical: {}", plain == watched); // true
+
+    // === LAZINESS: ONLY PULLED ITEMS ARE SEEN ===
+    // take(2) stops asking after two items pass the filter, so inspect never
+    // sees the rest of the input
+    let pulled = Cell::new(0);
+    let first_two: Vec<i32> = readings
+        .iter()
+        .inspect(|_| pulled.update(|n| n + 1))
+        .filter(|&&x| x > 5)
+        .take(2)
+        .copied()
+        .collect();
+    println!("\nfirst two above 5: {:?}", first_two); // [8, 12]
+    println!("inspected {} of {} readings", pulled.get(), readings.len()); // 4 of 7
+
+    // And with no consumer at all, nothing runs:
+    let lazy = readings
+        .iter()
+        .inspect(|x| eprintln!("never printed: {}", x));
+    println!("built an iterator, pulled nothing"); // no inspect output
+    drop(lazy);
+}
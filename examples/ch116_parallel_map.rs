@@ -0,0 +1,104 @@
+use std::thread;
+use std::time::Instant;
+
+fn main() {
+    // === SQUARING A BIG VECTOR ===
+    let data: Vec<u64> = (0..2_000_000).collect();
+
+    let start = Instant::now();
+    let sequential: Vec<u64> = data.iter().map(|x| expensive(*x)).collect();
+    let seq_time = start.elapsed();
+
+    let start = Instant::now();
+    let parallel = parallel_map(data, |x| expensive(*x));
+    let par_time = start.elapsed();
+
+    assert_eq!(parallel, sequential);
+    println!("{} results, identical and in order", parallel.len());
+    println!("sequential: {:>8.2?}", seq_time);
+    println!(
+        "parallel:   {:>8.2?}  (threads: {})",
+        par_time,
+        thread_count()
+    );
+
+    // === NOT JUST NUMBERS — ANY T: Send ===
+    let words: Vec<String> = ["scoped", "threads", "borrow", "safely"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    let lengths = parallel_map(words, |w| (w.to_uppercase(), w.len()));
+    println!("\n{:?}", lengths);
+
+    // === EDGE CASES ===
+    // Fewer items than threads: one thread per item, no empty chunks
+    let few = parallel_map_with(vec![1, 2], 8, |x| x * 100);
+    assert_eq!(few, vec![100, 200]);
+    println!("\nfew items: {:?}", few);
+
+    // Empty input: no threads spawned at all
+    let none: Vec<i32> = parallel_map(Vec::<i32>::new(), |x| x + 1);
+    assert!(none.is_empty());
+    println!("empty input: {:?}", none);
+}
+
+// Split `data` into one chunk per thread, map each chunk on its own
+// scoped thread, then stitch the results back together in order.
+//
+// Shared-nothing: every thread *owns* its chunk (T only needs Send, not
+// Sync) and builds its own output Vec — no locks, no shared mutable state.
+// The only thing shared is `f`, called from several threads at once,
+// hence F: Sync.
+fn parallel_map<T: Send, R: Send>(data: Vec<T>, f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    parallel_map_with(data, thread_count(), f)
+}
+
+// Same, with the thread count chosen by the caller
+fn parallel_map_with<T: Send, R: Send>(
+    data: Vec<T>,
+    threads: usize,
+    f: impl Fn(&T) -> R + Sync,
+) -> Vec<R> {
+    if data.is_empty() {
+        return Vec::new();
+    }
+    // Rounds up, so with fewer items than threads each chunk is one item
+    // and only data.len() threads are spawned
+    let chunk_size = data.len().div_ceil(threads.max(1));
+
+    // Cut the Vec into owned pieces: [0..c), [c..2c), ...
+    let mut chunks = Vec::new();
+    let mut rest = data;
+    while rest.len() > chunk_size {
+        let tail = rest.split_off(chunk_size);
+        chunks.push(rest);
+        rest = tail;
+    }
+    chunks.push(rest);
+
+    let f = &f;
+    thread::scope(|s| {
+        // Spawn them all first, *then* join — joining inside the same
+        // map would wait for each thread before starting the next
+        let handles: Vec<_> = chunks
+            .into_iter()
+            .map(|chunk| s.spawn(move || chunk.iter().map(f).collect::<Vec<R>>()))
+            .collect();
+
+        // Handles are in chunk order, so concatenating keeps input order
+        let mut results = Vec::new();
+        for handle in handles {
+            results.extend(handle.join().unwrap());
+        }
+        results
+    })
+}
+
+fn thread_count() -> usize {
+    thread::available_parallelism().map_or(4, |n| n.get())
+}
+
+// x² plus some busywork, so each item costs enough for threads to pay off
+fn expensive(x: u64) -> u64 {
+    (0..50).fold(x * x, |acc, i| acc.wrapping_add(i) ^ i)
+}
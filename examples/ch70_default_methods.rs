@@ -0,0 +1,76 @@
+fn main() {
+    // === USING THE DEFAULTS ===
+    // ConsoleLogger only provides level(); info() and error() come for free
+    let verbose = ConsoleLogger { min_level: INFO };
+    verbose.info("server started");   // printed
+    verbose.error("disk almost full"); // printed
+
+    // Same type, stricter level — the default methods respect it
+    let strict = ConsoleLogger { min_level: ERROR };
+    strict.info("cache warmed");      // skipped: below ERROR
+    strict.error("connection lost");  // printed
+
+    // === OVERRIDING ONE DEFAULT ===
+    // QuietLogger replaces info() but keeps the default error()
+    println!();
+    let quiet = QuietLogger;
+    quiet.info("you won't see this");
+    quiet.error("but you will see this");
+
+    // === THROUGH A GENERIC FUNCTION ===
+    // Callers don't care which methods were overridden
+    println!();
+    run_job(&verbose);
+    run_job(&quiet);
+}
+
+// Severity levels — higher means more important
+const INFO: u8 = 1;
+const ERROR: u8 = 2;
+
+trait Logger {
+    // REQUIRED — every implementor must say how verbose it is
+    fn level(&self) -> u8;
+
+    // DEFAULT — only prints if the logger's level allows INFO
+    fn info(&self, msg: &str) {
+        if self.level() <= INFO {
+            println!("[INFO]  {}", msg);
+        }
+    }
+
+    // DEFAULT — only prints if the logger's level allows ERROR
+    fn error(&self, msg: &str) {
+        if self.level() <= ERROR {
+            println!("[ERROR] {}", msg);
+        }
+    }
+}
+
+struct ConsoleLogger {
+    min_level: u8,
+}
+
+// Only the required method — both defaults are used as-is
+impl Logger for ConsoleLogger {
+    fn level(&self) -> u8 {
+        self.min_level
+    }
+}
+
+struct QuietLogger;
+
+impl Logger for QuietLogger {
+    fn level(&self) -> u8 {
+        INFO
+    }
+
+    // Override: swallow info messages entirely
+    fn info(&self, _msg: &str) {}
+    // error() still uses the default implementation
+}
+
+fn run_job(logger: &impl Logger) {
+    logger.info("job running");
+    logger.error("job failed");
+}
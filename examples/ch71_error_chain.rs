@@ -0,0 +1,96 @@
+use std::error::Error;
+use std::fmt;
+use std::num::ParseIntError;
+
+fn main() {
+    // === SUCCESS ===
+    let good = "port=8080\nworkers=4";
+    match load_config(good) {
+        Ok(settings) => println!("loaded: {:?}", settings),
+        Err(e) => report(&e),
+    }
+
+    // === FAILURE WITH A CAUSE CHAIN ===
+    // Line 2 has a bad number: ConfigError → SettingError → ParseIntError
+    let bad = "port=8080\nworkers=four";
+    println!();
+    match load_config(bad) {
+        Ok(settings) => println!("loaded: {:?}", settings),
+        Err(e) => report(&e),
+    }
+
+    // === THE BOTTOM OF THE CHAIN ===
+    // ParseIntError has no cause of its own — source() returns None
+    let root = "x".parse::<u32>().unwrap_err();
+    println!("\nroot source is None: {}", root.source().is_none());
+}
+
+// Walk source() until it returns None, printing each level
+fn report(err: &dyn Error) {
+    println!("error: {}", err);
+    let mut current = err.source();
+    let mut depth = 1;
+    while let Some(cause) = current {
+        println!("{}caused by: {}", "  ".repeat(depth), cause);
+        current = cause.source();
+        depth += 1;
+    }
+}
+
+// === LOW LEVEL: ONE key=value SETTING ===
+#[derive(Debug)]
+struct SettingError {
+    key: String,
+    source: ParseIntError,
+}
+
+impl fmt::Display for SettingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid value for '{}'", self.key)
+    }
+}
+
+impl Error for SettingError {
+    // Expose the wrapped error as the cause
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+fn parse_setting(line: &str) -> Result<(String, u32), SettingError> {
+    let (key, value) = line.split_once('=').unwrap_or((line, ""));
+    let number = value.trim().parse::<u32>().map_err(|e| SettingError {
+        key: key.trim().to_string(),
+        source: e,
+    })?;
+    Ok((key.trim().to_string(), number))
+}
+
+// === HIGH LEVEL: THE WHOLE CONFIG ===
+#[derive(Debug)]
+struct ConfigError {
+    line: usize,
+    source: SettingError,
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "failed to load config at line {}", self.line)
+    }
+}
+
+impl Error for ConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+fn load_config(text: &str) -> Result<Vec<(String, u32)>, ConfigError> {
+    let mut settings = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        // Add context: which line failed
+        let setting = parse_setting(line).map_err(|e| ConfigError { line: i + 1, source: e })?;
+        settings.push(setting);
+    }
+    Ok(settings)
+}
@@ -0,0 +1,89 @@
+use std::fmt;
+use std::num::{ParseFloatError, ParseIntError};
+use std::str::ParseBoolError;
+
+fn main() {
+    // === ALL THREE SUCCEED ===
+    println!("{:?}", register("30", "1.75", "true"));
+
+    // === EACH SOURCE FAILING IN TURN ===
+    // Every error arrives as MyError, whatever its original type
+    let cases = [
+        ("thirty", "1.75", "true"), // ParseIntError
+        ("30", "tall", "true"),     // ParseFloatError
+        ("30", "1.75", "yes"),      // ParseBoolError
+    ];
+    for (age, height, active) in cases {
+        match register(age, height, active) {
+            Ok(()) => println!("ok"),
+            Err(e) => println!("error: {}", e),
+        }
+    }
+}
+
+// === THREE FUNCTIONS, THREE ERROR TYPES ===
+
+fn parse_age(s: &str) -> Result<u32, ParseIntError> {
+    s.parse()
+}
+
+fn parse_height(s: &str) -> Result<f64, ParseFloatError> {
+    s.parse()
+}
+
+fn parse_active(s: &str) -> Result<bool, ParseBoolError> {
+    s.parse()
+}
+
+// === ONE ERROR TYPE TO RULE THEM ALL ===
+#[derive(Debug)]
+enum MyError {
+    Age(ParseIntError),
+    Height(ParseFloatError),
+    Active(ParseBoolError),
+}
+
+impl fmt::Display for MyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MyError::Age(e) => write!(f, "bad age: {}", e),
+            MyError::Height(e) => write!(f, "bad height: {}", e),
+            MyError::Active(e) => write!(f, "bad active flag: {}", e),
+        }
+    }
+}
+
+// One From impl per source error type
+impl From<ParseIntError> for MyError {
+    fn from(e: ParseIntError) -> Self {
+        MyError::Age(e)
+    }
+}
+
+impl From<ParseFloatError> for MyError {
+    fn from(e: ParseFloatError) -> Self {
+        MyError::Height(e)
+    }
+}
+
+impl From<ParseBoolError> for MyError {
+    fn from(e: ParseBoolError) -> Self {
+        MyError::Active(e)
+    }
+}
+
+// `?` calls From::from on the error automatically, so each line
+// converts its own error type into MyError
+fn register(age: &str, height: &str, active: &str) -> Result<(), MyError> {
+    let age = parse_age(age)?;
+    let height = parse_height(height)?;
+    let active = parse_active(active)?;
+
+    // Without the From impls you'd write this on every line:
+    // let age = parse_age(age).map_err(MyError::Age)?;
+    // let height = parse_height(height).map_err(MyError::Height)?;
+    // let active = parse_active(active).map_err(MyError::Active)?;
+
+    println!("registered: age {}, height {}m, active {}", age, height, active);
+    Ok(())
+}
@@ -0,0 +1,58 @@
+fn main() {
+    // === CLASSIFYING BY SHAPE ===
+    let lists: [&[i32]; 5] = [&[], &[7], &[1, 2], &[1, 2, 3], &[5, 6, 7, 8, 9]];
+    for list in lists {
+        println!("{:?} → {}", list, classify(list));
+    }
+
+    // === FIRST AND LAST WITH .. IN THE MIDDLE ===
+    println!();
+    for list in lists {
+        describe_ends(list);
+    }
+    // Edge case: for [1, 2] the pattern [first, .., last] still matches —
+    // `..` happily matches zero elements, so first = 1, last = 2
+
+    // === CAPTURING THE TAIL WITH @ ===
+    println!();
+    let nums = [10, 20, 30, 40];
+    if let [a, b, rest @ ..] = nums.as_slice() {
+        println!("a = {}, b = {}, rest = {:?}", a, b, rest); // rest = [30, 40]
+    }
+
+    // === MATCHING ONLY THE LAST ELEMENT ===
+    let path = ["home", "alice", "notes.txt"];
+    if let [.., file] = path.as_slice() {
+        println!("file name: {}", file);
+    }
+
+    // === RECURSION WITH SLICE PATTERNS ===
+    println!("sum: {}", sum(&nums)); // 100
+}
+
+fn classify(list: &[i32]) -> &'static str {
+    match list {
+        [] => "empty",
+        [_] => "singleton",
+        [_, _] => "pair",
+        [_, _, ..] => "longer",
+    }
+}
+
+fn describe_ends(list: &[i32]) {
+    match list {
+        [] => println!("nothing to describe"),
+        [only] => println!("just {}", only),
+        [first, middle @ .., last] => {
+            println!("first {}, last {}, {} in between", first, last, middle.len())
+        }
+    }
+}
+
+// Peel off the head and recurse on the tail
+fn sum(list: &[i32]) -> i32 {
+    match list {
+        [] => 0,
+        [head, tail @ ..] => head + sum(tail),
+    }
+}
@@ -0,0 +1,64 @@
+use std::ops::{Deref, DerefMut};
+
+fn main() {
+    // === * ON A CUSTOM SMART POINTER ===
+    let x = 5;
+    let y = MyBox::new(x);
+    assert_eq!(5, *y); // *y is really *(y.deref())
+    println!("*MyBox::new(5) = {}", *y);
+
+    // === DEREF COERCION ===
+    // hello() takes &str, but we pass &MyBox<String>
+    // The compiler inserts .deref() calls until the types match:
+    //   &MyBox<String> → &String   (our Deref impl)
+    //   &String        → &str      (String's Deref impl)
+    let name = MyBox::new(String::from("Rust"));
+    hello(&name);
+
+    // Without coercion you'd have to write this yourself:
+    hello(&(*name)[..]);
+
+    // Methods of the inner type are reachable too
+    println!("len via auto-deref: {}", name.len());
+
+    // === DEREFMUT ===
+    // &mut MyBox<String> coerces to &mut String, so String's
+    // mutating methods work directly
+    let mut greeting = MyBox::new(String::from("Hello"));
+    greeting.push_str(", world");
+    shout(&mut greeting);
+    println!("{}", *greeting);
+}
+
+// Tuple struct holding one value
+struct MyBox<T>(T);
+
+impl<T> MyBox<T> {
+    fn new(x: T) -> MyBox<T> {
+        MyBox(x)
+    }
+}
+
+// Deref lets *my_box and auto-deref reach the inner value
+impl<T> Deref for MyBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+// DerefMut is the mutable counterpart — requires Deref first
+impl<T> DerefMut for MyBox<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+fn hello(name: &str) {
+    println!("Hello, {}!", name);
+}
+
+fn shout(s: &mut String) {
+    s.push('!');
+}
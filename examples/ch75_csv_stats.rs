@@ -0,0 +1,99 @@
+// Capstone: a tiny CSV → stats pipeline.
+// Ties together string parsing (ch07), iterators (ch12),
+// error handling (ch08) and inline modules (ch04).
+
+mod csv {
+    // Splits one CSV line into trimmed fields (no quoting support)
+    pub fn parse_line(line: &str) -> Vec<&str> {
+        line.split(',').map(|field| field.trim()).collect()
+    }
+}
+
+mod stats {
+    #[derive(Debug, Default)]
+    pub struct Stats {
+        pub count: usize,
+        pub total: f64,
+        pub mean: f64,
+        pub top: Option<(String, f64)>,
+    }
+
+    // Empty input gives zeroed stats and no top scorer
+    pub fn summarize(rows: &[(String, f64)]) -> Stats {
+        if rows.is_empty() {
+            return Stats::default();
+        }
+        let total: f64 = rows.iter().map(|(_, score)| score).sum();
+        let top = rows
+            .iter()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .cloned();
+        Stats {
+            count: rows.len(),
+            total,
+            mean: total / rows.len() as f64,
+            top,
+        }
+    }
+}
+
+fn main() {
+    // === HAPPY PATH ===
+    let data = "name,score
+alice,91.5
+bob,78
+
+carol,88
+dave,95";
+    run("good data", data);
+
+    // === A BAD SCORE SURFACES AS AN ERROR WITH ITS LINE NUMBER ===
+    let bad = "name,score
+alice,91.5
+bob,lots
+carol,88";
+    run("bad data", bad);
+
+    // === ONLY A HEADER — ZEROED STATS ===
+    run("empty body", "name,score\n");
+}
+
+fn run(label: &str, input: &str) {
+    println!("=== {} ===", label);
+    match parse_rows(input) {
+        Ok(rows) => {
+            let s = stats::summarize(&rows);
+            println!("rows: {}, total: {:.1}, mean: {:.2}", s.count, s.total, s.mean);
+            match s.top {
+                Some((name, score)) => println!("top scorer: {} ({})", name, score),
+                None => println!("top scorer: none"),
+            }
+        }
+        Err(e) => println!("error: {}", e),
+    }
+    println!();
+}
+
+// Parse every data line, stopping at the first error.
+// Collecting an iterator of Result into Result<Vec<_>, _> does exactly that.
+fn parse_rows(input: &str) -> Result<Vec<(String, f64)>, String> {
+    input
+        .lines()
+        .enumerate()
+        .skip(1) // header row
+        .filter(|(_, line)| !line.trim().is_empty()) // blank lines
+        .map(|(i, line)| parse_row(i + 1, line))
+        .collect()
+}
+
+fn parse_row(line_no: usize, line: &str) -> Result<(String, f64), String> {
+    match csv::parse_line(line).as_slice() {
+        [name, score] => {
+            let score: f64 = score
+                .parse()
+                .map_err(|_| format!("line {}: '{}' is not a number", line_no, score))?;
+            Ok((name.to_string(), score))
+        }
+        fields => Err(format!("line {}: expected 2 fields, got {}", line_no, fields.len())),
+    }
+}
@@ -0,0 +1,84 @@
+// The three lifetime elision rules:
+// 1. Each reference parameter gets its own lifetime
+// 2. If there's exactly one input lifetime, it's used for all outputs
+// 3. If one of the inputs is &self or &mut self, its lifetime is used for outputs
+// If the rules can't pin down the output lifetime, you must write it yourself.
+
+fn main() {
+    // === RULE 2: ONE INPUT REFERENCE ===
+    let sentence = String::from("hello brave new world");
+    println!("first word: {}", first_word(&sentence));
+    println!("trimmed: '{}'", trim_dots("...done..."));
+
+    // === RULE 3: &self METHODS ===
+    let doc = Document {
+        title: String::from("Lifetimes"),
+        body: String::from("References must not outlive their data."),
+    };
+    println!("title: {}", doc.title());
+    println!("pick: {}", doc.pick("a much longer string than the title"));
+
+    // === ELISION FAILS: TWO INPUTS, ONE OUTPUT ===
+    let a = String::from("short");
+    let b = String::from("much longer");
+    println!("longest: {}", longest(&a, &b));
+
+    // === ONLY ONE INPUT MATTERS ===
+    println!("prefix: {}", prefix_of(&sentence, "hello"));
+}
+
+// Elided:    fn first_word(s: &str) -> &str
+// Desugared: fn first_word<'a>(s: &'a str) -> &'a str
+// Rule 1 gives `s` lifetime 'a, rule 2 copies it to the output
+fn first_word(s: &str) -> &str {
+    s.split_whitespace().next().unwrap_or("")
+}
+
+// Elided:    fn trim_dots(s: &str) -> &str
+// Desugared: fn trim_dots<'a>(s: &'a str) -> &'a str
+fn trim_dots(s: &str) -> &str {
+    s.trim_matches('.')
+}
+
+struct Document {
+    title: String,
+    body: String,
+}
+
+impl Document {
+    // Elided:    fn title(&self) -> &str
+    // Desugared: fn title<'a>(&'a self) -> &'a str
+    fn title(&self) -> &str {
+        &self.title
+    }
+
+    // Two references in, but rule 3 still applies: the output gets
+    // self's lifetime. That's why we can't return `other` here —
+    // try it and the compiler will complain.
+    // Desugared: fn pick<'a, 'b>(&'a self, other: &'b str) -> &'a str
+    fn pick(&self, other: &str) -> &str {
+        if self.body.len() >= other.len() {
+            &self.body
+        } else {
+            &self.title
+        }
+    }
+}
+
+// COUNTEREXAMPLE: this does NOT compile without annotations
+//
+//     fn longest(x: &str, y: &str) -> &str { ... }
+//     error[E0106]: missing lifetime specifier
+//
+// Rule 1 gives x and y separate lifetimes, rule 2 doesn't apply (two
+// inputs), rule 3 doesn't apply (no self). The compiler can't know
+// whether the result borrows from x or y, so we say: both, for 'a.
+fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    if x.len() >= y.len() { x } else { y }
+}
+
+// Two inputs, but the output only ever borrows from `s` —
+// so only `s` needs to share the output's lifetime
+fn prefix_of<'a>(s: &'a str, prefix: &str) -> &'a str {
+    if s.starts_with(prefix) { &s[..prefix.len()] } else { "" }
+}
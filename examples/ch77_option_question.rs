@@ -0,0 +1,45 @@
+use std::collections::HashMap;
+
+fn main() {
+    // === ? ON A SINGLE OPTION ===
+    println!("{:?}", first_char_upper("rust")); // Some('R')
+    println!("{:?}", first_char_upper(""));     // None — short-circuited
+
+    // === CHAINING SEVERAL ? STEPS ===
+    let mut config = HashMap::new();
+    config.insert("port", "8080");
+    config.insert("timeout", "soon");
+    config.insert("hosts", "alpha,beta");
+
+    // Every step can produce None; the first one that does wins
+    println!("\nport:    {:?}", port_plus_one(&config, "port"));    // Some(8081)
+    println!("missing: {:?}", port_plus_one(&config, "retries")); // None: key absent
+    println!("timeout: {:?}", port_plus_one(&config, "timeout")); // None: not a number
+
+    // === DIGGING THROUGH NESTED DATA ===
+    println!("\nsecond host initial: {:?}", second_host_initial(&config)); // Some('B')
+    config.insert("hosts", "alpha");
+    println!("with one host:       {:?}", second_host_initial(&config)); // None
+}
+
+// ? on Option: if None, return None immediately; if Some(x), unwrap to x
+fn first_char_upper(s: &str) -> Option<char> {
+    let c = s.chars().next()?; // empty string → return None here
+    Some(c.to_ascii_uppercase())
+}
+
+// Look up a key, parse it, do arithmetic — each step may fail
+fn port_plus_one(config: &HashMap<&str, &str>, key: &str) -> Option<u16> {
+    let raw = config.get(key)?;          // None if key missing
+    let port: u16 = raw.parse().ok()?;   // .ok() turns Result into Option
+    port.checked_add(1)                  // None on overflow
+}
+
+fn second_host_initial(config: &HashMap<&str, &str>) -> Option<char> {
+    let hosts = config.get("hosts")?;
+    let second = hosts.split(',').nth(1)?;
+    first_char_upper(second) // already returns Option — no ? needed
+}
+
+// Note: ? can't mix Option and Result in the same function.
+// Convert with .ok() (Result → Option) or .ok_or(err) (Option → Result).
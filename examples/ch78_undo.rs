@@ -0,0 +1,82 @@
+fn main() {
+    let mut editor = Editor::new();
+
+    // === APPLYING COMMANDS ===
+    editor.execute(Command::Insert(String::from("Hello")));
+    editor.execute(Command::Insert(String::from(", world")));
+    editor.execute(Command::Delete(5)); // backspace "world"
+    editor.execute(Command::Insert(String::from("Rust!")));
+    println!("document: '{}'", editor.doc); // Hello, Rust!
+
+    // === UNDOING STEP BY STEP ===
+    while editor.can_undo() {
+        editor.undo();
+        println!("undo → '{}'", editor.doc);
+    }
+
+    // === EDGE CASE: UNDO WITH NO HISTORY ===
+    editor.undo(); // nothing happens
+    println!("extra undo → '{}'", editor.doc);
+
+    // === EDGE CASE: DELETE MORE THAN EXISTS ===
+    editor.execute(Command::Insert(String::from("héllo")));
+    editor.execute(Command::Delete(100)); // clamped to 5 chars
+    println!("\nafter big delete: '{}'", editor.doc);
+    editor.undo(); // restores exactly what was removed
+    println!("undo → '{}'", editor.doc);
+}
+
+// Each command acts on the end of the document, like typing and backspace
+#[derive(Debug)]
+enum Command {
+    Insert(String),
+    Delete(usize), // number of chars to remove
+}
+
+struct Editor {
+    doc: String,
+    // For each executed command we store the command that REVERSES it.
+    // Undo is then just "pop and apply".
+    history: Vec<Command>,
+}
+
+impl Editor {
+    fn new() -> Editor {
+        Editor { doc: String::new(), history: Vec::new() }
+    }
+
+    fn execute(&mut self, cmd: Command) {
+        let inverse = self.apply(cmd);
+        self.history.push(inverse);
+    }
+
+    fn undo(&mut self) {
+        // Empty history → None → nothing to do
+        if let Some(inverse) = self.history.pop() {
+            self.apply(inverse);
+        }
+    }
+
+    fn can_undo(&self) -> bool {
+        !self.history.is_empty()
+    }
+
+    // Applies a command and returns the command that would undo it
+    fn apply(&mut self, cmd: Command) -> Command {
+        match cmd {
+            Command::Insert(text) => {
+                self.doc.push_str(&text);
+                Command::Delete(text.chars().count())
+            }
+            Command::Delete(n) => {
+                // Clamp to the document length (in chars, not bytes!)
+                let total = self.doc.chars().count();
+                let keep = total - n.min(total);
+                // Byte offset of the first char to remove
+                let cut = self.doc.char_indices().nth(keep).map_or(self.doc.len(), |(i, _)| i);
+                let removed = self.doc.split_off(cut);
+                Command::Insert(removed)
+            }
+        }
+    }
+}
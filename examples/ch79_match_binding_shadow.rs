@@ -0,0 +1,62 @@
+fn main() {
+    // === THE TRAP: A PATTERN VARIABLE SHADOWS, IT DOESN'T COMPARE ===
+    let x = 5;
+    let value = Some(10);
+
+    // Looks like "does value contain x?" — but `x` in the pattern is a
+    // NEW binding that matches anything and shadows the outer x
+    match value {
+        Some(x) => println!("matched Some(x) with x = {}  ← surprise! not 5", x),
+        None => println!("none"),
+    }
+    println!("outer x is still {}", x);
+
+    // === THE FIX: A MATCH GUARD ===
+    match value {
+        Some(n) if n == x => println!("value equals x"),
+        Some(n) => println!("value is {}, which is not x ({})", n, x),
+        None => println!("none"),
+    }
+
+    // Constants DO compare in patterns (they're not new bindings)
+    const LIMIT: i32 = 10;
+    match value {
+        Some(LIMIT) => println!("value hit the limit"),
+        _ => println!("value is under the limit"),
+    }
+
+    // === ref: BORROW INSTEAD OF MOVE ===
+    let name = Some(String::from("Ferris"));
+    if let Some(ref s) = name {
+        println!("\nborrowed: {}", s); // s: &String
+    }
+    println!("name still usable: {:?}", name);
+
+    // Matching on a reference does the same thing with less noise
+    // (default binding modes make `s` a &String automatically)
+    if let Some(s) = &name {
+        println!("also borrowed: {}", s);
+    }
+
+    // === ref mut: MUTATE IN PLACE ===
+    let mut score = Some(String::from("level 1"));
+    if let Some(ref mut s) = score {
+        s.push_str(" complete"); // s: &mut String
+    }
+    println!("score: {:?}", score);
+
+    // === MOVING OUT INVALIDATES THE ORIGINAL ===
+    let owned = Some(String::from("moved away"));
+    if let Some(s) = owned {
+        println!("\ntook ownership of: {}", s); // String moved into s
+    }
+    // println!("{:?}", owned);
+    // ERROR: borrow of moved value: `owned`
+    // The String was moved into `s`, which was dropped at the end of the block.
+
+    // Copy types don't have this problem — matching copies them
+    let n = Some(3);
+    if let Some(v) = n {
+        println!("copied {}, original still {:?}", v, n);
+    }
+}
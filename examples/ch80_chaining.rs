@@ -0,0 +1,50 @@
+fn main() {
+    // === CHAIN: ONE ITERATOR FROM THREE SOURCES ===
+    let from_vec = vec![1, 2, 3];
+    let from_array = [10, 20];
+    let all: Vec<i32> = from_vec
+        .into_iter()
+        .chain(from_array.iter().copied()) // a slice of an array
+        .chain(100..103)                   // a range
+        .collect();
+    println!("chain: {:?}", all); // [1, 2, 3, 10, 20, 100, 101, 102]
+
+    // === REV ===
+    let reversed: Vec<i32> = all.iter().rev().copied().collect();
+    println!("rev: {:?}", reversed);
+
+    // === STEP_BY ===
+    let every_other: Vec<i32> = all.iter().step_by(2).copied().collect();
+    println!("step_by(2): {:?}", every_other); // [1, 3, 20, 101]
+
+    // === CYCLE + TAKE ===
+    // cycle() is infinite — always bound it with take()
+    let pattern: String = ['-', '='].iter().cycle().take(9).collect();
+    println!("cycle: {}", pattern); // -=-=-=-=-
+
+    // Edge case: cycling an empty iterator ends immediately
+    // (there's nothing to repeat, so it doesn't loop forever)
+    let empty: Vec<i32> = Vec::new();
+    let nothing: Vec<i32> = empty.iter().cycle().take(5).copied().collect();
+    println!("cycle on empty: {:?}", nothing); // []
+
+    // === DEDUP-LIKE WITH WINDOWS ===
+    // windows(2) yields overlapping pairs; keep the first item plus
+    // every item that differs from the one before it
+    let noisy = [1, 1, 2, 2, 2, 3, 1, 1];
+    let deduped: Vec<i32> = noisy
+        .first()
+        .into_iter()
+        .copied()
+        .chain(noisy.windows(2).filter(|w| w[0] != w[1]).map(|w| w[1]))
+        .collect();
+    println!("windows dedup: {:?}", deduped); // [1, 2, 3, 1]
+
+    // === PUTTING IT TOGETHER ===
+    let mixed: Vec<i32> = (1..=3)
+        .chain(vec![7, 8, 9])
+        .rev()
+        .step_by(2)
+        .collect();
+    println!("chain + rev + step_by: {:?}", mixed); // [9, 7, 2]
+}
@@ -0,0 +1,59 @@
+fn main() {
+    // === 2D VECTORS ===
+    let a = Vector::<2>::new([3.0, 4.0]);
+    let b = Vector::new([1.0, 2.0]); // N inferred from the array length
+    println!("a = {:?}, |a| = {}", a.data, a.length()); // 5
+    println!("a + b = {:?}", a.add(&b).data);
+    println!("a · b = {}", a.dot(&b)); // 11
+
+    // === 3D VECTORS — SAME CODE, DIFFERENT N ===
+    let x = Vector::<3>::new([1.0, 0.0, 0.0]);
+    let y = Vector::<3>::new([0.0, 1.0, 0.0]);
+    println!("\nx · y = {}", x.dot(&y)); // 0 — perpendicular
+    println!("x + y = {:?}", x.add(&y).data);
+    println!("|x + y| = {:.4}", x.add(&y).length()); // √2
+
+    // === ZEROS ===
+    let origin = Vector::<4>::zeros();
+    println!("\nzeros::<4>: {:?}, length {}", origin.data, origin.length());
+
+    // === MISMATCHED DIMENSIONS DON'T COMPILE ===
+    // a.add(&x);
+    // ERROR: expected `&Vector<2>`, found `&Vector<3>`
+    // The length is part of the type, so this is caught before the
+    // program ever runs — no runtime length checks needed.
+}
+
+// N is a value (not a type) known at compile time.
+// Vector<2> and Vector<3> are completely different types.
+#[derive(Debug, Clone, Copy)]
+struct Vector<const N: usize> {
+    data: [f64; N],
+}
+
+impl<const N: usize> Vector<N> {
+    fn new(data: [f64; N]) -> Self {
+        Vector { data }
+    }
+
+    fn zeros() -> Self {
+        Vector { data: [0.0; N] }
+    }
+
+    // Both sides must have the same N — enforced by the signature
+    fn dot(&self, other: &Vector<N>) -> f64 {
+        self.data.iter().zip(other.data.iter()).map(|(a, b)| a * b).sum()
+    }
+
+    fn add(&self, other: &Vector<N>) -> Vector<N> {
+        let mut data = [0.0; N];
+        for (i, slot) in data.iter_mut().enumerate() {
+            *slot = self.data[i] + other.data[i];
+        }
+        Vector { data }
+    }
+
+    fn length(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+}
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+fn main() {
+    // === CLONING A String: A FULL COPY ===
+    let original = String::from("a fairly long piece of configuration text");
+    let copy = original.clone(); // new heap allocation + memcpy
+    println!(
+        "String clone — same address? {}",
+        std::ptr::eq(original.as_ptr(), copy.as_ptr()) // false
+    );
+
+    // === CLONING AN Arc<str>: JUST A COUNTER BUMP ===
+    let shared: Arc<str> = Arc::from("a fairly long piece of configuration text");
+    let another = Arc::clone(&shared); // no copy of the text at all
+    println!(
+        "Arc<str> clone — same address? {}",
+        std::ptr::eq(shared.as_ptr(), another.as_ptr()) // true
+    );
+    println!("strong_count: {}", Arc::strong_count(&shared)); // 2
+
+    // === SHARING ACROSS MANY INSTANCES ===
+    let region: Arc<str> = Arc::from("eu-west-1");
+    let servers: Vec<Server> = (1..=3)
+        .map(|id| Server { id, region: Arc::clone(&region) })
+        .collect();
+    for s in &servers {
+        println!("server {} in {}", s.id, s.region);
+    }
+    // 1 (ours) + 3 (one per server)
+    println!("region strong_count: {}", Arc::strong_count(&region)); // 4
+
+    drop(servers);
+    println!("after dropping servers: {}", Arc::strong_count(&region)); // 1
+
+    // === String → Arc<str> WITH .into() ===
+    // Copies the text once into a new allocation sized exactly for it
+    // (no spare capacity), then every clone after that is free
+    let name = String::from("Ferris");
+    let name: Arc<str> = name.into();
+    println!("\nconverted: {} ({} bytes)", name, name.len());
+
+    // Arc<str> derefs to &str, so it works anywhere &str does
+    greet(&name);
+}
+
+// When to prefer Arc<str> over String:
+// - the text never changes after creation (Arc<str> is immutable)
+// - many owners need it, possibly across threads
+// - you'd otherwise clone the String a lot
+// If you need to mutate it, or there's only one owner, use String.
+// (Rc<str> is the same idea for single-threaded code.)
+struct Server {
+    id: u32,
+    region: Arc<str>,
+}
+
+fn greet(name: &str) {
+    println!("hello, {}", name);
+}
@@ -0,0 +1,59 @@
+use std::any::Any;
+
+fn main() {
+    // === A VEC OF ANYTHING ===
+    // Box<dyn Any> erases the concrete type — but remembers it at runtime
+    let items: Vec<Box<dyn Any>> = vec![
+        Box::new(42i32),
+        Box::new(String::from("hello")),
+        Box::new(3.5f64),
+        Box::new(-7i32),
+        Box::new('x'),
+    ];
+
+    // === DOWNCAST_REF: GET THE CONCRETE TYPE BACK ===
+    // Returns Some(&T) if the value really is a T, None otherwise
+    print!("i32s only: ");
+    for item in &items {
+        if let Some(n) = item.downcast_ref::<i32>() {
+            print!("{} ", n);
+        }
+    }
+    println!();
+
+    // === IS::<T>() ===
+    let string_count = items.iter().filter(|item| item.is::<String>()).count();
+    println!("number of Strings: {}", string_count); // 1
+
+    // === FAILED DOWNCAST ===
+    // The first item is an i32 — asking for an i64 doesn't convert it
+    let as_i64 = items[0].downcast_ref::<i64>();
+    println!("items[0] as i64: {:?}", as_i64); // None
+
+    // === DESCRIBING EACH ITEM ===
+    for item in &items {
+        println!("  {}", describe(item.as_ref()));
+    }
+}
+
+fn describe(value: &dyn Any) -> String {
+    if let Some(n) = value.downcast_ref::<i32>() {
+        format!("i32: {}", n)
+    } else if let Some(s) = value.downcast_ref::<String>() {
+        format!("String: {:?}", s)
+    } else if let Some(f) = value.downcast_ref::<f64>() {
+        format!("f64: {}", f)
+    } else {
+        String::from("something else")
+    }
+}
+
+// Any is an escape hatch. Every new type means another downcast branch,
+// and the compiler can't tell you when you've missed one — the
+// "something else" arm above silently swallows them.
+// When you know the set of types up front, prefer an enum:
+//
+//     enum Value { Int(i32), Text(String), Float(f64) }
+//
+// Then `match` is exhaustive and checked at compile time. Reach for Any
+// only when the set of types is genuinely open (plugins, type-keyed maps).
@@ -0,0 +1,42 @@
+use std::fmt::Debug;
+
+fn main() {
+    // === ONE FUNCTION, UNRELATED TYPES ===
+    // i32, String and Point share nothing except the traits we ask for
+    println!("{}", describe(3, 3));
+    println!("{}", describe(String::from("cat"), String::from("dog")));
+    println!("{}", describe(Point { x: 1, y: 2 }, Point { x: 1, y: 2 }));
+
+    // Same behaviour via the `where` version
+    println!("{}", describe_where(vec![1, 2], vec![1, 2, 3]));
+
+    // === WHAT IF A BOUND IS MISSING? ===
+    // struct NoDebug;
+    // describe(NoDebug, NoDebug);
+    // ERROR: `NoDebug` doesn't implement `Debug`
+}
+
+// INLINE BOUNDS — fine when the list is short
+fn describe<T: Debug + Clone + PartialEq>(a: T, b: T) -> String {
+    let a_copy = a.clone(); // needs Clone
+    let relation = if a == b { "==" } else { "!=" }; // needs PartialEq
+    format!("{:?} {} {:?} (cloned: {:?})", a, relation, b, a_copy) // needs Debug
+}
+
+// WHERE CLAUSE — same meaning, but the signature stays readable
+// as bounds (or type parameters) pile up
+fn describe_where<T>(a: T, b: T) -> String
+where
+    T: Debug + Clone + PartialEq,
+{
+    let a_copy = a.clone();
+    let relation = if a == b { "==" } else { "!=" };
+    format!("{:?} {} {:?} (cloned: {:?})", a, relation, b, a_copy)
+}
+
+// A custom type opts in by deriving the three traits
+#[derive(Debug, Clone, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
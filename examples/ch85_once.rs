@@ -0,0 +1,61 @@
+use std::cell::{Cell, OnceCell};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::OnceLock;
+use std::thread;
+
+fn main() {
+    // === OnceCell: SINGLE-THREADED LAZY VALUE ===
+    let calls = Cell::new(0);
+    let report: OnceCell<String> = OnceCell::new();
+
+    println!("before: {:?}", report.get()); // None — nothing computed yet
+
+    for _ in 0..3 {
+        // The closure only runs the first time
+        let value = report.get_or_init(|| {
+            calls.set(calls.get() + 1);
+            println!("  (building the report...)");
+            String::from("quarterly totals")
+        });
+        println!("got: {}", value);
+    }
+    println!("initializer ran {} time(s)", calls.get()); // 1
+
+    // === OnceLock: A LAZY GLOBAL ===
+    // statics must be Sync, which OnceCell isn't — OnceLock is
+    println!("\nconfig: {}", config());
+    println!("config again: {}", config());
+    println!("config initializer ran {} time(s)", CONFIG_INITS.load(Ordering::SeqCst)); // 1
+
+    // === OnceLock UNDER CONCURRENT ACCESS ===
+    // Eight threads race to read it first; exactly one runs the closure,
+    // the others block until the value is ready
+    let handles: Vec<_> = (0..8)
+        .map(|_| thread::spawn(|| table().len()))
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+    println!("\ntable built {} time(s) across 8 threads", TABLE_INITS.load(Ordering::SeqCst)); // 1
+}
+
+static CONFIG: OnceLock<String> = OnceLock::new();
+static CONFIG_INITS: AtomicUsize = AtomicUsize::new(0);
+
+fn config() -> &'static str {
+    CONFIG.get_or_init(|| {
+        CONFIG_INITS.fetch_add(1, Ordering::SeqCst);
+        String::from("mode=production")
+    })
+}
+
+static TABLE: OnceLock<Vec<u64>> = OnceLock::new();
+static TABLE_INITS: AtomicUsize = AtomicUsize::new(0);
+
+fn table() -> &'static Vec<u64> {
+    TABLE.get_or_init(|| {
+        TABLE_INITS.fetch_add(1, Ordering::SeqCst);
+        thread::sleep(std::time::Duration::from_millis(20)); // pretend it's slow
+        (0..10).map(|n| n * n).collect()
+    })
+}
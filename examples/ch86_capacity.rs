@@ -0,0 +1,53 @@
+fn main() {
+    // === LEN VS CAPACITY ===
+    // len = how many elements are stored
+    // capacity = how many fit before the Vec must reallocate
+    let mut v: Vec<i32> = Vec::new();
+    println!("new: len {}, capacity {}", v.len(), v.capacity()); // 0, 0
+
+    // === THE GROWTH PATTERN ===
+    // When full, the Vec allocates a bigger buffer (roughly double),
+    // copies everything over, and frees the old one
+    let mut last_cap = v.capacity();
+    for i in 0..100 {
+        v.push(i);
+        if v.capacity() != last_cap {
+            println!("  push #{:<3} → reallocated: capacity {} → {}", i + 1, last_cap, v.capacity());
+            last_cap = v.capacity();
+        }
+    }
+
+    // === WITH_CAPACITY: ALLOCATE ONCE UP FRONT ===
+    let mut sized: Vec<i32> = Vec::with_capacity(100);
+    let start_cap = sized.capacity();
+    for i in 0..100 {
+        sized.push(i);
+    }
+    println!(
+        "\nwith_capacity(100): capacity {} → {} (no reallocations)",
+        start_cap,
+        sized.capacity()
+    );
+
+    // === RESERVE: MAKE ROOM FOR AT LEAST N MORE ===
+    sized.reserve(50);
+    println!("after reserve(50): len {}, capacity >= 150? {}", sized.len(), sized.capacity() >= 150);
+
+    // === CLEAR KEEPS THE ALLOCATION ===
+    sized.clear();
+    println!("after clear: len {}, capacity {}", sized.len(), sized.capacity());
+
+    // === SHRINK_TO_FIT: GIVE MEMORY BACK ===
+    sized.extend([1, 2, 3]);
+    sized.shrink_to_fit();
+    println!("after shrink_to_fit: len {}, capacity {}", sized.len(), sized.capacity()); // 3, 3
+}
+
+// Why with_capacity matters: every reallocation allocates, copies every
+// element, and frees the old buffer. Pushing 1,000,000 items into an
+// empty Vec triggers ~20 of those. If you know the final size (or a good
+// upper bound), Vec::with_capacity(n) does a single allocation instead.
+// The same goes for String::with_capacity and HashMap::with_capacity.
+//
+// Note: the exact growth factor is an implementation detail — don't
+// write code that depends on capacity being a power of two.
@@ -0,0 +1,106 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+fn main() {
+    // === DERIVED: EXACT, CASE-SENSITIVE ===
+    // #[derive(PartialEq, Eq, Hash)] compares field by field
+    let a = Tag(String::from("Rust"));
+    let b = Tag(String::from("rust"));
+    println!("derived: Tag(\"Rust\") == Tag(\"rust\")? {}", a == b); // false
+
+    // === MANUAL: CASE-INSENSITIVE ===
+    let hello = CaseInsensitiveStr::new("Hello");
+    let lower = CaseInsensitiveStr::new("hello");
+    println!("manual: \"Hello\" == \"hello\"? {}", hello == lower); // true
+    println!("same hash? {}", hash_of(&hello) == hash_of(&lower)); // true
+
+    // === AS A HASHMAP KEY ===
+    let mut headers = HashMap::new();
+    headers.insert(CaseInsensitiveStr::new("Content-Type"), "text/html");
+    headers.insert(CaseInsensitiveStr::new("X-Request-Id"), "abc123");
+
+    // Look up with different casing
+    println!("\ncontent-type → {:?}", headers.get(&CaseInsensitiveStr::new("content-type")));
+    println!("X-REQUEST-ID → {:?}", headers.get(&CaseInsensitiveStr::new("X-REQUEST-ID")));
+
+    // Inserting with another casing replaces, not duplicates
+    headers.insert(CaseInsensitiveStr::new("CONTENT-TYPE"), "application/json");
+    println!("entries: {}", headers.len()); // 2
+}
+
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct Tag(String);
+
+// Keeps the original spelling for display, but compares and hashes
+// ignoring ASCII case
+#[derive(Debug, Clone)]
+struct CaseInsensitiveStr(String);
+
+impl CaseInsensitiveStr {
+    fn new(s: &str) -> Self {
+        CaseInsensitiveStr(s.to_string())
+    }
+}
+
+impl PartialEq for CaseInsensitiveStr {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.eq_ignore_ascii_case(&other.0)
+    }
+}
+
+// Eq has no methods — it's a promise that eq is reflexive
+// (a == a always), which holds here
+impl Eq for CaseInsensitiveStr {}
+
+// THE HASH/EQ CONTRACT: if a == b then hash(a) == hash(b).
+// eq() ignores case, so hash() must ignore case too — hash the
+// lowercased bytes. If we derived Hash instead, "Hello" and "hello"
+// would be equal but land in different buckets, and HashMap lookups
+// would randomly fail.
+impl Hash for CaseInsensitiveStr {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for byte in self.0.bytes() {
+            state.write_u8(byte.to_ascii_lowercase());
+        }
+        // Terminator, like str's own Hash impl, so ("ab", "c") and
+        // ("a", "bc") in a tuple key don't collide
+        state.write_u8(0xff);
+    }
+}
+
+fn hash_of<T: Hash>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+// Run with: cargo test --example ch87_hash_eq
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lookup_ignores_case() {
+        let mut map = HashMap::new();
+        map.insert(CaseInsensitiveStr::new("Hello"), 1);
+        assert_eq!(map.get(&CaseInsensitiveStr::new("hello")), Some(&1));
+        assert_eq!(map.get(&CaseInsensitiveStr::new("HELLO")), Some(&1));
+        assert_eq!(map.get(&CaseInsensitiveStr::new("help")), None);
+    }
+
+    #[test]
+    fn equal_values_hash_equally() {
+        let pairs = [("Hello", "hELLO"), ("ABC", "abc"), ("", "")];
+        for (a, b) in pairs {
+            let (a, b) = (CaseInsensitiveStr::new(a), CaseInsensitiveStr::new(b));
+            assert_eq!(a, b);
+            assert_eq!(hash_of(&a), hash_of(&b));
+        }
+    }
+
+    #[test]
+    fn different_values_not_equal() {
+        assert_ne!(CaseInsensitiveStr::new("Hello"), CaseInsensitiveStr::new("Hell"));
+    }
+}
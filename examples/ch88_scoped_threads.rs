@@ -0,0 +1,60 @@
+use std::thread;
+
+fn main() {
+    // === BORROWING LOCAL DATA FROM THREADS ===
+    let numbers: Vec<u64> = (1..=10).collect();
+    let thread_count = 3;
+
+    // 10 items over 3 threads doesn't divide evenly:
+    // chunk size rounds up to 4, giving slices of 4, 4 and 2
+    let chunk_size = numbers.len().div_ceil(thread_count);
+
+    let total: u64 = thread::scope(|s| {
+        let handles: Vec<_> = numbers
+            .chunks(chunk_size)
+            .enumerate()
+            .map(|(i, chunk)| {
+                // `chunk` is a &[u64] borrowed from `numbers` — no Arc, no clone
+                s.spawn(move || {
+                    let sum: u64 = chunk.iter().sum();
+                    println!("thread {} summed {:?} = {}", i, chunk, sum);
+                    sum
+                })
+            })
+            .collect();
+
+        // join() gives back each thread's return value
+        handles.into_iter().map(|h| h.join().unwrap()).sum()
+    });
+    // All scoped threads are guaranteed to have finished here
+
+    println!("total: {} (expected {})", total, numbers.iter().sum::<u64>());
+
+    // `numbers` is still ours — the threads only borrowed it
+    println!("numbers still usable: len {}", numbers.len());
+
+    // === MUTABLE BORROWS WORK TOO ===
+    // Each thread gets its own disjoint &mut slice
+    let mut data = vec![1, 2, 3, 4, 5, 6, 7];
+    thread::scope(|s| {
+        for chunk in data.chunks_mut(3) {
+            s.spawn(move || {
+                for x in chunk {
+                    *x *= 10;
+                }
+            });
+        }
+    });
+    println!("\nmultiplied in place: {:?}", data);
+}
+
+// Why not thread::spawn?
+//
+//     let numbers = vec![1, 2, 3];
+//     thread::spawn(|| println!("{:?}", numbers));
+//     ERROR: closure may outlive the current function, but it borrows `numbers`
+//
+// A plain spawned thread could keep running after main's stack frame is
+// gone, so it requires 'static data: you must `move` ownership in, or
+// share through Arc. thread::scope joins every thread before it returns,
+// so the compiler knows the borrows can't dangle.
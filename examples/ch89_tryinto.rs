@@ -0,0 +1,41 @@
+// TryFrom and TryInto are in the prelude since the 2021 edition — no `use` needed
+use std::num::TryFromIntError;
+
+fn main() {
+    // === `as` SILENTLY TRUNCATES ===
+    let big: i32 = 300;
+    let truncated = big as u8; // keeps only the low 8 bits: 300 - 256
+    println!("300i32 as u8 = {}", truncated); // 44 — no warning, no error!
+
+    let negative: i32 = -1;
+    println!("-1i32 as u32 = {}", negative as u32); // 4294967295
+
+    // === TRY_FROM REFUSES TO LOSE DATA ===
+    println!("\nu8::try_from(300i32) = {:?}", u8::try_from(300i32)); // Err(...)
+    println!("i32::try_from(42u64) = {:?}", i32::try_from(42u64));   // Ok(42)
+
+    // === EDGE CASES ===
+    println!("\nu8::try_from(255) = {:?}", u8::try_from(255i32)); // Ok(255) — exactly u8::MAX
+    println!("u8::try_from(256) = {:?}", u8::try_from(256i32)); // Err
+    println!("u32::try_from(-5) = {:?}", u32::try_from(-5i64)); // Err — negative
+
+    // === TRY_INTO: THE SAME THING FROM THE OTHER SIDE ===
+    // Like From/Into: implementing TryFrom gives you TryInto for free
+    let value: i64 = 1_000;
+    let result: Result<u16, _> = value.try_into();
+    println!("\n1000i64 into u16 = {:?}", result);
+
+    // === VALIDATING BEFORE NARROWING ===
+    for raw in [80, 443, 70_000, -1] {
+        match to_port(raw) {
+            Ok(port) => println!("port {} ok", port),
+            Err(e) => println!("{} rejected: {}", raw, e),
+        }
+    }
+}
+
+// The caller gets an error instead of a wrong port number
+fn to_port(raw: i64) -> Result<u16, TryFromIntError> {
+    let port: u16 = raw.try_into()?;
+    Ok(port)
+}
@@ -0,0 +1,45 @@
+fn main() {
+    let mut v = vec![3, 8, 1, 9];
+    v.push(4);
+
+    // === SUM AND PRODUCT NEED A TYPE ===
+    // sum() can produce many types, so Rust needs to know which one:
+    // either annotate the variable or use the turbofish
+    let total: i32 = v.iter().sum();
+    let total2 = v.iter().sum::<i32>();
+    println!("sum: {} / {}", total, total2); // 25
+    // let bad = v.iter().sum(); // ERROR: type annotations needed
+
+    let factorial = (1..=10u64).product::<u64>();
+    println!("10! = {}", factorial);
+    // product overflows quickly: (1..=21u64).product::<u64>() panics in
+    // debug builds. Use u128, or try_fold with checked_mul, if in doubt.
+
+    // === COUNT, MIN, MAX, LAST, NTH ===
+    // (for a plain Vec, v.len() is the direct way — count() shines after filters)
+    println!("\ncount > 3: {}", v.iter().filter(|&&x| x > 3).count()); // 3
+    println!("min: {:?}", v.iter().min());           // Some(1)
+    println!("max: {:?}", v.iter().max());           // Some(9)
+    println!("last: {:?}", v.iter().last());         // Some(4)
+    println!("nth(2): {:?}", (10..20).nth(2));         // Some(12) — zero-based
+    println!("nth(99): {:?}", (10..20).nth(99));       // None
+
+    // === SEARCHING ===
+    println!("\nposition of 9: {:?}", v.iter().position(|&x| x == 9)); // Some(3)
+    println!("first even: {:?}", v.iter().find(|&&x| x % 2 == 0));   // Some(8)
+    println!("any > 8: {}", v.iter().any(|&x| x > 8));                // true
+    println!("all > 0: {}", v.iter().all(|&x| x > 0));                // true
+
+    // Ranges work the same way
+    println!("first square over 50: {:?}", (1..).map(|n| n * n).find(|&sq| sq > 50)); // Some(64)
+
+    // === EMPTY ITERATORS ===
+    let empty: Vec<i32> = Vec::new();
+    println!("\nempty min: {:?}", empty.iter().min());           // None
+    println!("empty max: {:?}", empty.iter().max());             // None
+    println!("empty sum: {}", empty.iter().sum::<i32>());        // 0
+    println!("empty product: {}", empty.iter().product::<i32>()); // 1
+    println!("empty any: {}", empty.iter().any(|&x| x > 0));     // false
+    // all() on nothing is vacuously true — there's no element that fails
+    println!("empty all: {}", empty.iter().all(|&x| x > 1000));  // true
+}
@@ -0,0 +1,61 @@
+use std::cell::{Cell, RefCell};
+
+fn main() {
+    // === MUTATING THROUGH &self ===
+    // `page` is not declared mut, yet its counter changes
+    let page = Page::new("/home");
+    page.visit();
+    page.visit();
+    page.visit();
+    println!("{} visited {} times", page.path, page.hits.get()); // 3
+
+    // === GET / SET / REPLACE / UPDATE ===
+    let c = Cell::new(10);
+    println!("\nget: {}", c.get()); // 10 — a copy of the value
+
+    c.set(20);
+    println!("after set: {}", c.get()); // 20
+
+    let old = c.replace(30); // swap in a new value, get the old one back
+    println!("replace returned {}, now {}", old, c.get()); // 20, 30
+
+    c.update(|n| n * 2); // read-modify-write in one call
+    println!("after update: {}", c.get()); // 60
+
+    // === CELL VS REFCELL ===
+    // Cell never hands out references — it only copies values in and out.
+    // So there's nothing to track and nothing that can panic.
+    //
+    // RefCell DOES hand out references (borrow / borrow_mut) and checks
+    // the borrowing rules at runtime. Break them and it panics:
+    let shared = RefCell::new(vec![1, 2, 3]);
+    let reader = shared.borrow();
+    println!("\nRefCell try_borrow_mut while reading: {:?}", shared.try_borrow_mut().is_err()); // true
+    drop(reader);
+
+    // === CELL WORKS BEST WITH COPY TYPES ===
+    // get() needs T: Copy. For a non-Copy type like String you can only
+    // move values in and out wholesale:
+    let name = Cell::new(String::from("ferris"));
+    // let r: &String = ...;  // no way to borrow the inside
+    // name.get();            // ERROR: String is not Copy
+    let taken = name.take(); // leaves String::default() behind
+    println!("\ntook {:?}, left {:?}", taken, name.take()); // "ferris", ""
+    // When you need &T or &mut T to the contents, use RefCell instead.
+}
+
+struct Page {
+    path: String,
+    hits: Cell<u32>,
+}
+
+impl Page {
+    fn new(path: &str) -> Page {
+        Page { path: path.to_string(), hits: Cell::new(0) }
+    }
+
+    // &self, not &mut self — callers can count visits through a shared reference
+    fn visit(&self) {
+        self.hits.update(|n| n + 1);
+    }
+}
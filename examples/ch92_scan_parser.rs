@@ -0,0 +1,43 @@
+fn main() {
+    // === WATCHING THE DEPTH CHANGE ===
+    let input = "(a(b)c)";
+    println!("{}", input);
+    for (i, c, depth) in depths(input) {
+        println!("  [{}] '{}' depth {}", i, c, depth);
+    }
+
+    // === VALIDATING ===
+    println!();
+    for s in ["(a(b)c)", "(a))", "((x)", "no brackets", ""] {
+        println!("{:<14} → {}", format!("{:?}", s), check(s));
+    }
+}
+
+// scan is like fold, but yields every intermediate state.
+// The closure gets `&mut state` plus the next item and returns
+// Some(output) to keep going (or None to stop early).
+fn depths(s: &str) -> impl Iterator<Item = (usize, char, i32)> + '_ {
+    s.char_indices().scan(0, |depth, (i, c)| {
+        match c {
+            '(' => *depth += 1,
+            ')' => *depth -= 1,
+            _ => {}
+        }
+        Some((i, c, *depth))
+    })
+}
+
+fn check(s: &str) -> String {
+    // A ')' with nothing to close drives depth below zero —
+    // find() stops the scan right there
+    if let Some((i, _, _)) = depths(s).find(|&(_, _, depth)| depth < 0) {
+        return format!("unbalanced: unexpected ')' at index {}", i);
+    }
+
+    // Never went negative, but it must also end back at zero.
+    // last() on an empty string is None, which means depth 0.
+    match depths(s).last().map_or(0, |(_, _, depth)| depth) {
+        0 => String::from("balanced"),
+        open => format!("unbalanced: {} unclosed '('", open),
+    }
+}
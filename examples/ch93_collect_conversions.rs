@@ -0,0 +1,49 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+fn main() {
+    let words = ["apple", "banana", "apple", "cherry", "banana", "apple"];
+
+    // === ONE METHOD, MANY TARGETS ===
+    // collect() works for any type implementing FromIterator —
+    // the target type decides what gets built
+
+    let long_words: Vec<&str> = words.iter().copied().filter(|w| w.len() > 5).collect();
+    println!("Vec:     {:?}", long_words);
+
+    let as_set: HashSet<&str> = words.iter().copied().collect(); // duplicates vanish
+    println!("HashSet: {} unique words", as_set.len()); // 3
+
+    let lengths: HashMap<&str, usize> = words.iter().map(|w| (*w, w.len())).collect();
+    println!("HashMap: banana has {} letters", lengths["banana"]);
+
+    let initials: String = words.iter().filter_map(|w| w.chars().next()).collect();
+    println!("String:  {}", initials); // abacba
+
+    // BTreeMap keeps keys sorted
+    let sorted: BTreeMap<&str, usize> = words.iter().map(|w| (*w, w.len())).collect();
+    println!("BTreeMap: {:?}", sorted);
+
+    // The turbofish works too, instead of annotating the variable
+    let upper = words.iter().map(|w| w.to_uppercase()).collect::<Vec<_>>();
+    println!("turbofish: {:?}", upper);
+
+    // === Vec<(K, V)> → HashMap → SORTED Vec ===
+    let pairs = vec![("carol", 88), ("alice", 95), ("bob", 72)];
+    let scores: HashMap<&str, i32> = pairs.into_iter().collect();
+    println!("\nscores map has {} entries", scores.len());
+
+    let mut back: Vec<(&str, i32)> = scores.into_iter().collect();
+    back.sort(); // HashMap order is random — sort for a stable result
+    println!("back to sorted Vec: {:?}", back);
+
+    // === DUPLICATE KEYS: THE LAST ONE WINS ===
+    // Each pair is inserted in order, so later values overwrite earlier ones
+    let updates = [("x", 1), ("y", 2), ("x", 3)];
+    let latest: HashMap<&str, i32> = updates.into_iter().collect();
+    println!("\nduplicate key 'x' → {}", latest["x"]); // 3
+
+    // === EVEN RESULTS COLLECT ===
+    // Result<Vec<_>, _> stops at the first error
+    let parsed: Result<Vec<i32>, _> = ["1", "2", "3"].iter().map(|s| s.parse::<i32>()).collect();
+    println!("parsed: {:?}", parsed); // Ok([1, 2, 3])
+}
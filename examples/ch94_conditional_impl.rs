@@ -0,0 +1,95 @@
+use std::fmt::Display;
+
+fn main() {
+    // === METHODS EVERY Wrapper HAS ===
+    let num = Wrapper::new(42);
+    let opaque = Wrapper::new(Secret { code: 7 });
+    println!("inner: {}", num.inner());
+    println!("inner code: {}", opaque.inner().code);
+
+    // === ONLY WHEN T: Display ===
+    num.print(); // i32 implements Display
+    Wrapper::new("hello").print(); // so does &str
+
+    // opaque.print();
+    // ERROR: the method `print` exists for struct `Wrapper<Secret>`,
+    //        but its trait bounds were not satisfied
+    //        (`Secret: std::fmt::Display` is not satisfied)
+
+    // === ONLY WHEN T IS AN ITERATOR OF i32 ===
+    let range = Wrapper::new(1..=10);
+    println!("sum of 1..=10: {}", range.sum()); // 55
+
+    let evens = Wrapper::new(vec![2, 4, 6].into_iter());
+    println!("sum of evens: {}", evens.sum()); // 12
+
+    // num.sum();
+    // ERROR: `i32` is not an iterator
+
+    // === BOTH AT ONCE ===
+    // A type can satisfy several blocks — these impls stack up
+    let both = Wrapper::new(Countdown(3));
+    both.print();
+    println!("countdown sum: {}", both.sum()); // 3 + 2 + 1
+}
+
+struct Wrapper<T> {
+    value: T,
+}
+
+// Always available, whatever T is
+impl<T> Wrapper<T> {
+    fn new(value: T) -> Self {
+        Wrapper { value }
+    }
+
+    fn inner(&self) -> &T {
+        &self.value
+    }
+}
+
+// Only exists for T: Display
+impl<T: Display> Wrapper<T> {
+    fn print(&self) {
+        println!("[{}]", self.value);
+    }
+}
+
+// Only exists for iterators yielding i32. A `where` clause reads better
+// once the bound includes an associated type like Item = i32.
+impl<T> Wrapper<T>
+where
+    T: Iterator<Item = i32>,
+{
+    // Takes self by value: summing consumes the iterator
+    fn sum(self) -> i32 {
+        self.value.sum()
+    }
+}
+
+// Deliberately not Display
+struct Secret {
+    code: u32,
+}
+
+// Both Display AND Iterator<Item = i32>, so it gets print() and sum()
+struct Countdown(i32);
+
+impl Display for Countdown {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "countdown from {}", self.0)
+    }
+}
+
+impl Iterator for Countdown {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<i32> {
+        if self.0 > 0 {
+            self.0 -= 1;
+            Some(self.0 + 1)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use std::fmt;
+
+fn main() {
+    // === EACH VARIANT RENDERS ITS OWN WAY ===
+    let messages = vec![
+        Message::Quit,
+        Message::Move { x: 10, y: 20 },
+        Message::Write(String::from("hello")),
+        Message::ChangeColor(255, 0, 128),
+    ];
+
+    // Vec<T> itself has no Display (there's no single "right" way to
+    // show a list), so print each element with {}
+    for msg in &messages {
+        println!("{}", msg);
+    }
+
+    // === DISPLAY GIVES YOU to_string() AND format! FOR FREE ===
+    let log_line = format!("[{}] {}", messages.len(), messages[1]);
+    println!("\n{}", log_line);
+
+    let as_strings: Vec<String> = messages.iter().map(|m| m.to_string()).collect();
+    println!("joined: {}", as_strings.join(" | "));
+
+    // Width/alignment flags work when the impl uses f.pad()
+    println!("\n|{:>12}|", Message::Quit);
+
+    // Debug is still there for developers
+    println!("debug: {:?}", messages[2]);
+}
+
+// Same enum as ch06
+#[derive(Debug)]
+enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Write(String),
+    ChangeColor(u8, u8, u8),
+}
+
+// Instead of ch06's call() method that println!s directly, Display
+// produces the text and lets the caller decide where it goes —
+// stdout, a log file, a String for a test...
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            // pad() respects width/alignment flags like {:>12}
+            Message::Quit => f.pad("quit"),
+            Message::Move { x, y } => write!(f, "move to ({}, {})", x, y),
+            Message::Write(text) => write!(f, "write \"{}\"", text),
+            Message::ChangeColor(r, g, b) => write!(f, "color #{:02x}{:02x}{:02x}", r, g, b),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+fn main() {
+    // === parse() WORKS FOR ANY FromStr TYPE ===
+    // Same call as "42".parse::<i32>() in ch08 — just a different target
+    let p: Point = "3,4".parse().unwrap();
+    println!("parsed: {:?}", p);
+
+    // Turbofish form
+    let q = " -7 , 12 ".parse::<Point>();
+    println!("with spaces: {:?}", q);
+
+    // === HANDLING ERRORS ===
+    for input in ["10,20", "10 20", "ten,20", "1,2,3", ""] {
+        let quoted = format!("{:?}", input);
+        match input.parse::<Point>() {
+            Ok(point) => println!("{:>8} → {:?}", quoted, point),
+            Err(e) => println!("{:>8} → error: {}", quoted, e),
+        }
+    }
+
+    // === COLLECTING MANY ===
+    let path: Result<Vec<Point>, _> = ["0,0", "1,2", "3,5"].iter().map(|s| s.parse()).collect();
+    println!("\npath: {:?}", path);
+}
+
+#[derive(Debug, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Debug, PartialEq)]
+enum ParsePointError {
+    MissingComma,
+    TooManyFields(usize),
+    BadNumber(ParseIntError),
+}
+
+impl fmt::Display for ParsePointError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParsePointError::MissingComma => write!(f, "expected 'x,y' but found no comma"),
+            ParsePointError::TooManyFields(n) => write!(f, "expected 2 fields, found {}", n),
+            ParsePointError::BadNumber(e) => write!(f, "bad coordinate: {}", e),
+        }
+    }
+}
+
+// Lets `?` turn a ParseIntError into our error type (see ch72)
+impl From<ParseIntError> for ParsePointError {
+    fn from(e: ParseIntError) -> Self {
+        ParsePointError::BadNumber(e)
+    }
+}
+
+// Implementing FromStr is all it takes to make str::parse work
+impl FromStr for Point {
+    type Err = ParsePointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<&str> = s.split(',').collect();
+        match fields.as_slice() {
+            [x, y] => Ok(Point { x: x.trim().parse()?, y: y.trim().parse()? }),
+            [_] => Err(ParsePointError::MissingComma),
+            more => Err(ParsePointError::TooManyFields(more.len())),
+        }
+    }
+}
+
+// Run with: cargo test --example ch96_fromstr
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_parse() {
+        assert_eq!("3,4".parse(), Ok(Point { x: 3, y: 4 }));
+        assert_eq!(" -1 , 0 ".parse(), Ok(Point { x: -1, y: 0 }));
+    }
+
+    #[test]
+    fn missing_comma() {
+        assert_eq!("3 4".parse::<Point>(), Err(ParsePointError::MissingComma));
+    }
+
+    #[test]
+    fn bad_number() {
+        assert!(matches!("3,four".parse::<Point>(), Err(ParsePointError::BadNumber(_))));
+        assert!(matches!(",4".parse::<Point>(), Err(ParsePointError::BadNumber(_))));
+    }
+
+    #[test]
+    fn extra_fields() {
+        assert_eq!("1,2,3".parse::<Point>(), Err(ParsePointError::TooManyFields(3)));
+    }
+}
@@ -0,0 +1,91 @@
+fn main() {
+    // === COUNTING RUNS ===
+    let runs: Vec<(char, usize)> = "aaabbc".chars().run_lengths().collect();
+    println!("{:?}", runs); // [('a', 3), ('b', 2), ('c', 1)]
+
+    // Works on any iterator whose items can be compared and cloned
+    let temps = [20, 20, 21, 21, 21, 20];
+    for (t, n) in temps.iter().run_lengths() {
+        println!("{}°C × {}", t, n);
+    }
+
+    // === RUN-LENGTH ENCODING IN ONE LINE ===
+    // The same idea as an RLE compressor, built from adapters
+    let encoded: String = "WWWWBBBWWC"
+        .chars()
+        .run_lengths()
+        .map(|(c, n)| format!("{}{}", n, c))
+        .collect();
+    println!("encoded: {}", encoded); // 4W3B2W1C
+
+    // === LAZY — ONLY PULLS WHAT IT NEEDS ===
+    let first = (1..).map(|n| n / 3).run_lengths().nth(2);
+    println!("third run of n/3: {:?}", first); // Some((2, 3))
+}
+
+// The adapter: holds the inner iterator plus one item of lookahead.
+// A run only ends when we see a *different* item, so that item has to
+// be remembered and used to start the next run.
+struct RunLengths<I: Iterator> {
+    iter: I,
+    pending: Option<I::Item>,
+}
+
+impl<I> Iterator for RunLengths<I>
+where
+    I: Iterator,
+    I::Item: PartialEq + Clone,
+{
+    type Item = (I::Item, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Start with the leftover item, or pull a fresh one
+        let current = self.pending.take().or_else(|| self.iter.next())?;
+        let mut count = 1;
+
+        for item in self.iter.by_ref() {
+            if item == current {
+                count += 1;
+            } else {
+                self.pending = Some(item);
+                break;
+            }
+        }
+
+        Some((current, count))
+    }
+}
+
+// Extension trait — same pattern as the DedupExt module
+trait RunLengthExt: Iterator + Sized {
+    fn run_lengths(self) -> RunLengths<Self> {
+        RunLengths { iter: self, pending: None }
+    }
+}
+
+// Blanket impl: every iterator gets .run_lengths()
+impl<I: Iterator> RunLengthExt for I {}
+
+// Run with: cargo test --example ch97_run_length_iter
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_run() {
+        let runs: Vec<(char, usize)> = "zzzz".chars().run_lengths().collect();
+        assert_eq!(runs, vec![('z', 4)]);
+    }
+
+    #[test]
+    fn alternating_items_each_count_one() {
+        let runs: Vec<(i32, usize)> = [1, 2, 1, 2].into_iter().run_lengths().collect();
+        assert_eq!(runs, vec![(1, 1), (2, 1), (1, 1), (2, 1)]);
+    }
+
+    #[test]
+    fn empty_iterator() {
+        let runs: Vec<(char, usize)> = "".chars().run_lengths().collect();
+        assert!(runs.is_empty());
+    }
+}
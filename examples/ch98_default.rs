@@ -0,0 +1,93 @@
+fn main() {
+    // === DERIVED DEFAULT ===
+    // Every field gets its type's default: false, 0, "", None, empty Vec
+    let plain = Config::default();
+    println!("{:?}", plain);
+
+    // === ..Default::default() — OVERRIDE ONLY WHAT YOU CARE ABOUT ===
+    // Same syntax as `..user2` in ch05, but the "rest" comes from defaults
+    // instead of from another instance you had to build first
+    let noisy = Config {
+        verbose: true,
+        ..Default::default()
+    };
+    println!("{:?}", noisy);
+
+    let named = Config {
+        name: String::from("prod"),
+        tags: vec![String::from("eu"), String::from("blue")],
+        ..Default::default()
+    };
+    println!("{:?}", named);
+    println!(
+        "{} → verbose={}, level={}, output={:?}, {} tags",
+        named.name,
+        named.verbose,
+        named.level,
+        named.output,
+        named.tags.len()
+    );
+
+    // === HAND-WRITTEN DEFAULT — SENSIBLE NON-ZERO VALUES ===
+    // A timeout of 0 or 0 retries would be a useless default
+    let server = ServerConfig::default();
+    println!("\n{:?}", server); // port 8080, timeout 30, retries 3
+
+    let patient = ServerConfig {
+        timeout: 120,
+        ..Default::default()
+    };
+    println!("{:?}", patient); // only timeout changed
+    println!(
+        "connect to {}:{} — give up after {} tries of {}s",
+        patient.host, patient.port, patient.retries, patient.timeout
+    );
+
+    // === STANDARD LIBRARY DEFAULTS ===
+    let v: Vec<i32> = Vec::default();
+    let o: Option<u8> = Option::default();
+    let s = String::default();
+    let n = i64::default();
+    println!(
+        "\nVec: {:?}, Option: {:?}, String: {:?}, i64: {}",
+        v, o, s, n
+    ); // [], None, "", 0
+
+    // Handy with generics: "give me an empty/zero T"
+    println!("zero of f64: {}", zero_of::<f64>()); // 0
+    println!("zero of bool: {}", zero_of::<bool>()); // false
+}
+
+// Derive works when every field's type implements Default
+#[derive(Debug, Default)]
+struct Config {
+    name: String,
+    verbose: bool,
+    level: u8,
+    output: Option<String>,
+    tags: Vec<String>,
+}
+
+#[derive(Debug)]
+struct ServerConfig {
+    host: String,
+    port: u16,
+    timeout: u32, // seconds
+    retries: u8,
+}
+
+// Implement it yourself when "zero" isn't the right starting point
+impl Default for ServerConfig {
+    fn default() -> Self {
+        ServerConfig {
+            host: String::from("localhost"),
+            port: 8080,
+            timeout: 30,
+            retries: 3,
+        }
+    }
+}
+
+fn zero_of<T: Default>() -> T {
+    T::default()
+}
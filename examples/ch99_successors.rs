@@ -0,0 +1,62 @@
+use std::iter::{from_fn, successors};
+
+fn main() {
+    // === successors: "next value is computed from the previous one" ===
+    // No struct, no `impl Iterator` — just a first value and a step closure.
+    // The closure returns Option: Some(next) to continue, None to stop.
+
+    // === POWERS OF TWO — STOPS ON OVERFLOW ===
+    // checked_mul returns None once 2^64 won't fit, which ends the iterator
+    let powers: Vec<u64> = successors(Some(1u64), |&x| x.checked_mul(2)).collect();
+    println!("{} powers of two fit in a u64", powers.len()); // 64
+    println!("first five: {:?}", &powers[..5]); // [1, 2, 4, 8, 16]
+    println!("largest: {}", powers.last().unwrap()); // 9223372036854775808
+
+    // take_while to stop at a bound of our choosing instead
+    let small: Vec<u64> = successors(Some(1u64), |&x| x.checked_mul(2))
+        .take_while(|&x| x < 1000)
+        .collect();
+    println!("below 1000: {:?}", small);
+
+    // === COLLATZ ===
+    // Each step comes from a named fn instead of a closure
+    let collatz: Vec<u64> = successors(Some(6), collatz_step).collect();
+    println!("\ncollatz(6): {:?}", collatz); // [6, 3, 10, 5, 16, 8, 4, 2, 1]
+
+    // A plain fn works as the step too — it just has to take &T
+    let steps = successors(Some(27), collatz_step).count() - 1;
+    println!("collatz(27) reaches 1 after {} steps", steps); // 111
+
+    // === GEOMETRIC SERIES ===
+    // 1 + 1/2 + 1/4 + ... creeps toward 2
+    let terms: Vec<f64> = successors(Some(1.0f64), |&x| Some(x / 2.0))
+        .take_while(|&x| x > 1e-6)
+        .collect();
+    let sum: f64 = terms.iter().sum();
+    println!(
+        "\n{} terms of 1 + 1/2 + 1/4 + ... = {:.6}",
+        terms.len(),
+        sum
+    ); // ~2
+
+    // === from_fn: when state doesn't fit in one value ===
+    // Fibonacci needs two numbers of state, so keep them in the closure
+    let (mut a, mut b) = (0u32, 1u32);
+    let fib: Vec<u32> = from_fn(|| {
+        let current = a;
+        (a, b) = (b, a + b);
+        Some(current)
+    })
+    .take(10)
+    .collect();
+    println!("\nfibonacci: {:?}", fib); // [0, 1, 1, 2, 3, 5, 8, 13, 21, 34]
+}
+
+// n even → n/2, n odd → 3n+1; None once we hit 1 ends the sequence
+fn collatz_step(&n: &u64) -> Option<u64> {
+    match n {
+        1 => None,
+        n if n % 2 == 0 => Some(n / 2),
+        n => Some(3 * n + 1),
+    }
+}
@@ -0,0 +1,37 @@
+use rust_journey::char_slice::char_substring;
+
+fn main() {
+    let s = "héllo";
+
+    // === BYTES vs CHARS ===
+    println!(
+        "{:?} is {} chars but {} bytes",
+        s,
+        s.chars().count(),
+        s.len()
+    ); // 5 chars, 6 bytes
+    for (byte, c) in s.char_indices() {
+        println!("  byte {} → '{}'", byte, c);
+    }
+
+    // === SLICING BY CHAR POSITION ===
+    // Chars 1..3 — "él" — no need to know é is two bytes
+    println!("\nchars 1..3: {:?}", char_substring(s, 1, 2)); // Some("él")
+
+    // The byte-slice equivalent only works if you get the offsets exactly right
+    println!("bytes 1..4: {:?}", &s[1..4]); // "él"
+    // &s[1..2] would panic: byte 2 is inside 'é'
+    println!("is byte 2 a boundary? {}", s.is_char_boundary(2)); // false
+
+    // === OUT OF RANGE IS None, NOT A PANIC ===
+    println!("\nchars 4..6: {:?}", char_substring(s, 4, 2)); // None
+    println!("chars 9..10: {:?}", char_substring(s, 9, 1)); // None
+
+    // === ANY SCRIPT ===
+    let greeting = "こんにちは世界";
+    println!(
+        "\n{:?} → last two: {:?}",
+        greeting,
+        char_substring(greeting, 5, 2)
+    ); // Some("世界")
+}
@@ -0,0 +1,33 @@
+use rust_journey::checked_slice::CheckedSlice;
+
+fn main() {
+    let scores = vec![88, 92, 75, 64, 99];
+    let safe = CheckedSlice(&scores);
+
+    // === SINGLE ELEMENTS ===
+    for i in [1, 4, 7] {
+        match safe.at(i) {
+            Ok(score) => println!("scores[{}] = {}", i, score),
+            Err(e) => println!("scores[{}] → {}", i, e),
+        }
+    }
+
+    // === RANGES ===
+    for (l, r) in [(1, 3), (3, 1), (2, 10)] {
+        match safe.range(l, r) {
+            Ok(slice) => println!("scores[{}..{}] = {:?}", l, r, slice),
+            Err(e) => println!("scores[{}..{}] → {}", l, r, e),
+        }
+    }
+
+    // === WORKS WITH ? ===
+    println!("\n{:?}", first_two_average(&scores));
+    println!("{:?}", first_two_average(&[50]));
+}
+
+fn first_two_average(data: &[i32]) -> Result<f64, String> {
+    let safe = CheckedSlice(data);
+    let first = safe.at(0)?;
+    let second = safe.at(1)?;
+    Ok((first + second) as f64 / 2.0)
+}
@@ -0,0 +1,28 @@
+use rust_journey::compose::{compose, pipe};
+
+fn main() {
+    // === COMPOSE TWO FUNCTIONS ===
+    let add_one = |x: i32| x + 1;
+    let double = |x: i32| x * 2;
+    let add_then_double = compose(add_one, double);
+    println!("(3 + 1) * 2 = {}", add_then_double(3)); // 8
+
+    // Composition can change types along the way: &str → usize → String
+    let describe_len = compose(|s: &str| s.len(), |n| format!("{} chars", n));
+    println!("{}", describe_len("ferris"));
+
+    // And composed functions compose again
+    let shout_len = compose(describe_len, |s: String| s.to_uppercase());
+    println!("{}", shout_len("crab"));
+
+    // === A TEXT-CLEANING PIPELINE ===
+    let cleanup: Vec<Box<dyn Fn(String) -> String>> = vec![
+        Box::new(|s| s.trim().to_string()),
+        Box::new(|s| s.to_lowercase()),
+        Box::new(|s| s.split_whitespace().collect::<Vec<_>>().join("-")),
+    ];
+
+    for title in ["  Hello World  ", "Rust   Is FUN", "already-clean"] {
+        println!("{:?} → {:?}", title, pipe(&cleanup, title.to_string()));
+    }
+}
@@ -0,0 +1,19 @@
+use rust_journey::dedup_iter::DedupExt;
+
+fn main() {
+    // === BASIC USE ===
+    let readings = [1, 1, 2, 2, 2, 3, 1];
+    let changes: Vec<&i32> = readings.iter().dedup_consecutive().collect();
+    println!("{:?} → {:?}", readings, changes); // [1, 2, 3, 1]
+
+    // === DEBOUNCING A NOISY SIGNAL ===
+    // Only report when the button state actually changes
+    let samples = ["up", "up", "down", "down", "down", "up", "up"];
+    for state in samples.iter().dedup_consecutive() {
+        println!("button is now {}", state);
+    }
+
+    // === COMPOSES WITH OTHER ADAPTERS ===
+    let squeezed: String = "bookkeeper".chars().dedup_consecutive().collect();
+    println!("squeezed: {}", squeezed); // bokeper
+}
@@ -0,0 +1,36 @@
+use rust_journey::dijkstra::shortest_path;
+use std::collections::HashMap;
+
+fn main() {
+    // === A SMALL ROAD MAP ===
+    let towns = ["Harbor", "Mill", "Ridge", "Ford", "Castle"];
+    // (from, to, minutes) — roads run both ways
+    let roads = [
+        (0, 1, 4),
+        (0, 2, 9),
+        (1, 2, 3),
+        (1, 3, 12),
+        (2, 3, 2),
+        (3, 4, 5),
+        (2, 4, 15),
+    ];
+
+    let mut map: HashMap<u32, Vec<(u32, u32)>> = HashMap::new();
+    for (a, b, minutes) in roads {
+        map.entry(a).or_default().push((b, minutes));
+        map.entry(b).or_default().push((a, minutes));
+    }
+
+    // === HARBOR → CASTLE ===
+    match shortest_path(&map, 0, 4) {
+        Some((cost, path)) => {
+            let names: Vec<&str> = path.iter().map(|&n| towns[n as usize]).collect();
+            println!("{} ({} minutes)", names.join(" → "), cost); // Harbor → Mill → Ridge → Ford → Castle (14 minutes)
+        }
+        None => println!("no route"),
+    }
+
+    // === NOWHERE TO GO ===
+    // Node 99 isn't on the map at all
+    println!("to an island: {:?}", shortest_path(&map, 0, 99)); // None
+}
@@ -0,0 +1,37 @@
+use rust_journey::dsu::DisjointSet;
+use std::collections::BTreeMap;
+
+fn main() {
+    // === FRIEND GROUPS ===
+    let people = ["Ann", "Ben", "Cat", "Dan", "Eve", "Fay", "Gus"];
+    let friendships = [(0, 1), (1, 2), (3, 4), (5, 5)];
+
+    let mut groups = DisjointSet::new(people.len());
+    println!("start: {} separate groups", groups.count());
+
+    for (a, b) in friendships {
+        let merged = groups.union(a, b);
+        println!(
+            "{} + {} → {} ({} groups)",
+            people[a],
+            people[b],
+            if merged { "merged" } else { "already together" },
+            groups.count()
+        );
+    }
+
+    // === CONNECTIVITY ===
+    println!("\nAnn ~ Cat? {}", groups.connected(0, 2)); // true, via Ben
+    println!("Ann ~ Dan? {}", groups.connected(0, 3)); // false
+
+    // === LIST EACH GROUP ===
+    // BTreeMap so the output order is stable
+    let mut by_root: BTreeMap<usize, Vec<&str>> = BTreeMap::new();
+    for (i, name) in people.iter().enumerate() {
+        by_root.entry(groups.find(i)).or_default().push(name);
+    }
+    println!();
+    for members in by_root.values() {
+        println!("group: {}", members.join(", "));
+    }
+}
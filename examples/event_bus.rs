@@ -0,0 +1,35 @@
+use rust_journey::event_bus::EventBus;
+
+// Two unrelated event types — no shared trait needed
+struct UserSignedUp {
+    name: String,
+}
+
+struct OrderPlaced {
+    id: u32,
+    total: f64,
+}
+
+fn main() {
+    let mut bus = EventBus::new();
+
+    // === SUBSCRIBE BY TYPE ===
+    // The closure's parameter type picks which events it receives
+    bus.subscribe(|e: &UserSignedUp| println!("[email] welcome, {}!", e.name));
+    bus.subscribe(|e: &UserSignedUp| println!("[stats] new user: {}", e.name));
+    bus.subscribe(|e: &OrderPlaced| println!("[billing] order #{}: ${:.2}", e.id, e.total));
+
+    // === PUBLISH ===
+    // Only handlers for the published type fire
+    println!("publishing UserSignedUp:");
+    bus.publish(&UserSignedUp { name: String::from("alice") });
+
+    println!("\npublishing OrderPlaced:");
+    bus.publish(&OrderPlaced { id: 42, total: 19.99 });
+
+    // === NOBODY LISTENING ===
+    // Any 'static type can be published; with no subscribers nothing happens
+    println!("\npublishing a String (no subscribers):");
+    bus.publish(&String::from("ignored"));
+    println!("  (nothing)");
+}
@@ -0,0 +1,17 @@
+use rust_journey::freq::frequencies;
+
+fn main() {
+    // === LETTER FREQUENCIES ===
+    let text = "mississippi";
+    let letters: Vec<char> = text.chars().collect();
+
+    println!("letters in '{}':", text);
+    for (letter, count) in frequencies(&letters) {
+        println!("  {} {}", letter, "█".repeat(count));
+    }
+    // i and s tie at 4 — 'i' comes first because 'i' < 's'
+
+    // === WORKS FOR ANY Ord + Hash TYPE ===
+    let rolls = [6, 2, 6, 3, 2, 6, 1];
+    println!("\ndice rolls: {:?}", frequencies(&rolls));
+}
@@ -0,0 +1,34 @@
+use rust_journey::grid_ops::{rotate_90, transpose};
+use std::fmt::Display;
+
+fn main() {
+    let m = vec![vec![1, 2, 3], vec![4, 5, 6]];
+
+    // === TRANSPOSE ===
+    println!("original:");
+    show(&m);
+    println!("transposed:");
+    show(&transpose(&m)); // 1 4 / 2 5 / 3 6
+
+    // === ROTATE 90° CLOCKWISE ===
+    println!("rotated:");
+    show(&rotate_90(&m)); // 4 1 / 5 2 / 6 3
+
+    // === WORKS FOR ANY Clone TYPE ===
+    let art = vec![
+        vec!['#', '.', '.'],
+        vec!['#', '.', '.'],
+        vec!['#', '#', '#'],
+    ];
+    println!("an L:");
+    show(&art);
+    println!("turned:");
+    show(&rotate_90(&art));
+}
+
+fn show<T: Display>(m: &[Vec<T>]) {
+    for row in m {
+        let cells: Vec<String> = row.iter().map(|x| x.to_string()).collect();
+        println!("  {}", cells.join(" "));
+    }
+}
@@ -0,0 +1,24 @@
+use rust_journey::group::group_by;
+
+fn main() {
+    // === WORDS BY FIRST LETTER ===
+    let words = vec!["apple", "banana", "avocado", "blueberry", "cherry"];
+    let by_letter = group_by(words, |w| w.chars().next().unwrap_or(' '));
+
+    // HashMap order is random — sort the keys for stable output
+    let mut letters: Vec<_> = by_letter.keys().collect();
+    letters.sort();
+    for letter in letters {
+        println!("{}: {:?}", letter, by_letter[letter]);
+    }
+
+    // === NUMBERS BY PARITY ===
+    let numbers: Vec<i32> = (1..=10).collect();
+    let by_parity = group_by(numbers, |n| if n % 2 == 0 { "even" } else { "odd" });
+
+    let mut keys: Vec<_> = by_parity.keys().collect();
+    keys.sort();
+    for key in keys {
+        println!("{}: {:?}", key, by_parity[key]);
+    }
+}
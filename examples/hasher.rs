@@ -0,0 +1,77 @@
+use rust_journey::hasher::{Djb2, Fnv1a, HashStrategy, HashTable};
+
+fn main() {
+    let fruits = [
+        "apple",
+        "banana",
+        "cherry",
+        "date",
+        "elderberry",
+        "fig",
+        "grape",
+        "honeydew",
+        "kiwi",
+        "lemon",
+    ];
+
+    // === SAME TABLE CODE, TWO STRATEGIES ===
+    let mut by_fnv = HashTable::with_buckets(Fnv1a, 13);
+    let mut by_djb2 = HashTable::with_buckets(Djb2, 13);
+    for (i, fruit) in fruits.iter().enumerate() {
+        by_fnv.insert(fruit, i);
+        by_djb2.insert(fruit, i);
+    }
+
+    println!(
+        "fnv1a: {} entries, longest chain {}",
+        by_fnv.len(),
+        by_fnv.longest_chain()
+    );
+    println!(
+        "djb2:  {} entries, longest chain {}",
+        by_djb2.len(),
+        by_djb2.longest_chain()
+    );
+    println!(
+        "kiwi → {:?} / {:?}",
+        by_fnv.get("kiwi"),
+        by_djb2.get("kiwi")
+    ); // Some(8) both
+
+    // === THE RAW HASHES ===
+    println!("\n{:<8} {:>18} {:>18}", "key", "fnv1a", "djb2");
+    for key in ["a", "b", "ab", "ba"] {
+        println!(
+            "{:<8} {:>18x} {:>18x}",
+            key,
+            Fnv1a.hash(key.as_bytes()),
+            Djb2.hash(key.as_bytes())
+        );
+    }
+
+    // === PLUG IN YOUR OWN ===
+    // A deliberately bad strategy: only looks at the length. With keys
+    // "item0".."item99", 90 of them share a length — and so a bucket.
+    let keys: Vec<String> = (0..100).map(|i| format!("item{}", i)).collect();
+    let mut good = HashTable::with_buckets(Fnv1a, 13);
+    let mut bad = HashTable::with_buckets(LengthOnly, 13);
+    for (i, key) in keys.iter().enumerate() {
+        good.insert(key, i);
+        bad.insert(key, i);
+    }
+    println!("\n100 keys in 13 buckets:");
+    println!("  fnv1a longest chain:       {}", good.longest_chain());
+    println!("  length-only longest chain: {}", bad.longest_chain()); // 90
+    println!(
+        "  still correct, just slower: item42 → {:?}",
+        bad.get("item42")
+    );
+}
+
+struct LengthOnly;
+
+impl HashStrategy for LengthOnly {
+    fn hash(&self, data: &[u8]) -> u64 {
+        data.len() as u64
+    }
+}
@@ -0,0 +1,36 @@
+use rust_journey::huffman::{build_codes, decode, encode};
+use std::collections::HashMap;
+
+fn main() {
+    let text = "she sells sea shells by the sea shore";
+
+    // === COUNT, THEN BUILD CODES ===
+    let mut freqs: HashMap<char, u32> = HashMap::new();
+    for c in text.chars() {
+        *freqs.entry(c).or_insert(0) += 1;
+    }
+    let codes = build_codes(&freqs);
+
+    // Shortest codes first — they belong to the most common chars
+    let mut table: Vec<(&char, &String)> = codes.iter().collect();
+    table.sort_by_key(|&(c, code)| (code.len(), *c));
+    println!("char  count  code");
+    for (c, code) in table {
+        println!("{:>4?}  {:>5}  {}", c, freqs[c], code);
+    }
+
+    // === COMPRESS ===
+    let bits = encode(text, &codes).unwrap();
+    let original = text.len() * 8;
+    println!("\noriginal: {} bits ({} bytes × 8)", original, text.len());
+    println!("encoded:  {} bits", bits.len());
+    println!(
+        "saved {:.1}%",
+        100.0 * (1.0 - bits.len() as f64 / original as f64)
+    );
+    println!("first 32 bits: {}…", &bits[..32]);
+
+    // === AND BACK ===
+    let restored = decode(&bits, &codes).unwrap();
+    println!("\nround trip ok: {}", restored == text); // true
+}
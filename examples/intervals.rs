@@ -0,0 +1,24 @@
+use rust_journey::intervals::merge_intervals;
+
+fn main() {
+    // === BUSY TIMES FROM SEVERAL CALENDARS ===
+    // Minutes since midnight; order doesn't matter
+    let meetings = vec![
+        (14 * 60, 15 * 60),           // 14:00–15:00
+        (9 * 60, 10 * 60 + 30),       // 09:00–10:30
+        (10 * 60, 11 * 60),           // 10:00–11:00  overlaps the one above
+        (11 * 60, 11 * 60 + 30),      // 11:00–11:30  touches it
+        (14 * 60 + 15, 14 * 60 + 45), // 14:15–14:45  inside 14:00–15:00
+        (16 * 60, 17 * 60),           // 16:00–17:00
+    ];
+
+    println!("busy:");
+    for (start, end) in merge_intervals(meetings) {
+        println!("  {} – {}", clock(start), clock(end));
+    }
+    // 09:00 – 11:30, 14:00 – 15:00, 16:00 – 17:00
+}
+
+fn clock(minutes: i64) -> String {
+    format!("{:02}:{:02}", minutes / 60, minutes % 60)
+}
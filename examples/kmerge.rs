@@ -0,0 +1,19 @@
+use rust_journey::kmerge::merge_sorted;
+
+fn main() {
+    // === MERGING SORTED NUMBER LISTS ===
+    let lists = vec![vec![1, 4, 9, 12], vec![2, 3, 10], vec![], vec![0, 5, 6, 7]];
+    println!("inputs: {:?}", lists);
+    println!("merged: {:?}", merge_sorted(&lists));
+
+    // === MERGING TIMESTAMPED LOGS FROM SEVERAL SERVERS ===
+    // Tuples compare field by field, so sorting by (time, message) works
+    let web = vec![(1, "web: start"), (5, "web: request"), (9, "web: stop")];
+    let db = vec![(2, "db: start"), (6, "db: query")];
+    let cache = vec![(3, "cache: warm"), (7, "cache: hit")];
+
+    println!("\ntimeline:");
+    for (time, msg) in merge_sorted(&[web, db, cache]) {
+        println!("  t={} {}", time, msg);
+    }
+}
@@ -0,0 +1,35 @@
+use rust_journey::levenshtein::levenshtein;
+
+fn main() {
+    // === A FEW DISTANCES ===
+    for (a, b) in [
+        ("kitten", "sitting"),
+        ("flaw", "lawn"),
+        ("rust", "trust"),
+        ("naïve", "naive"),
+    ] {
+        println!("{:>7} → {:<7} {}", a, b, levenshtein(a, b));
+    }
+
+    // === "DID YOU MEAN...?" ===
+    let commands = [
+        "build", "check", "clean", "doc", "run", "test", "bench", "publish",
+    ];
+    for typo in ["biuld", "tset", "chekc", "publsh", "xyzzy"] {
+        // Closest command, ties going to the earlier one in the list
+        let (best, distance) = commands
+            .iter()
+            .map(|&c| (c, levenshtein(typo, c)))
+            .min_by_key(|&(_, d)| d)
+            .unwrap();
+        // Only suggest if it's plausibly a typo, not a different word
+        if distance <= 2 {
+            println!(
+                "{:>7}: did you mean '{}'? (distance {})",
+                typo, best, distance
+            );
+        } else {
+            println!("{:>7}: no such command", typo);
+        }
+    }
+}
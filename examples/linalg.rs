@@ -0,0 +1,34 @@
+use rust_journey::linalg::{determinant, inverse};
+
+fn main() {
+    let m = [[4.0, 7.0, 2.0], [3.0, 6.0, 1.0], [2.0, 5.0, 3.0]];
+
+    // === DETERMINANT ===
+    println!("m:");
+    show(&m);
+    println!("det(m) = {}", determinant(&m)); // 9
+
+    // === INVERSE ===
+    match inverse(&m) {
+        Some(inv) => {
+            println!("\ninverse:");
+            show(&inv);
+        }
+        None => println!("\nm is singular"),
+    }
+
+    // === SINGULAR MATRICES HAVE NO INVERSE ===
+    // Second row is twice the first
+    let flat = [[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 1.0]];
+    println!(
+        "\ndet = {}, inverse = {:?}",
+        determinant(&flat),
+        inverse(&flat)
+    ); // 0, None
+}
+
+fn show(m: &[[f64; 3]; 3]) {
+    for row in m {
+        println!("  [{:>7.3} {:>7.3} {:>7.3}]", row[0], row[1], row[2]);
+    }
+}
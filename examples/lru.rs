@@ -0,0 +1,24 @@
+use rust_journey::lru::LruCache;
+
+fn main() {
+    // === CAPACITY 2 ===
+    let mut cache = LruCache::new(2);
+    cache.put("alice", 31);
+    cache.put("bob", 27);
+    println!("cached alice and bob");
+
+    // Reading alice makes bob the least recently used
+    println!("get alice → {:?}", cache.get(&"alice"));
+
+    // A third key forces an eviction — bob goes, not alice
+    if let Some((key, value)) = cache.put("carol", 45) {
+        println!("put carol → evicted {} ({})", key, value);
+    }
+    println!("get bob → {:?}", cache.get(&"bob")); // None
+
+    // Now alice is the oldest
+    if let Some((key, _)) = cache.put("dave", 52) {
+        println!("put dave → evicted {}", key);
+    }
+    println!("has carol: {}, has dave: {}", cache.contains(&"carol"), cache.contains(&"dave"));
+}
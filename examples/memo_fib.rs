@@ -0,0 +1,21 @@
+use rust_journey::memo_fib::{fib, fib_checked, fib_naive};
+use std::time::Instant;
+
+fn main() {
+    let n = 40;
+
+    // === NAIVE: EXPONENTIAL ===
+    let start = Instant::now();
+    let slow = fib_naive(n);
+    println!("naive fib({}) = {} in {:?}", n, slow, start.elapsed());
+
+    // === MEMOIZED: LINEAR ===
+    let start = Instant::now();
+    let fast = fib(n);
+    println!("memo  fib({}) = {} in {:?}", n, fast, start.elapsed());
+
+    // === OVERFLOW ===
+    // fib(93) is the largest that fits in a u64
+    println!("\nfib_checked(93) = {:?}", fib_checked(93));
+    println!("fib_checked(94) = {:?}", fib_checked(94)); // None
+}
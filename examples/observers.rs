@@ -0,0 +1,58 @@
+use rust_journey::observers::{Observer, Subject};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+fn main() {
+    let mut thermometer = Subject::new();
+
+    // === TWO DISPLAYS WATCH THE SAME SENSOR ===
+    let lobby = Rc::new(RefCell::new(Display::new("lobby")));
+    let office = Rc::new(RefCell::new(Display::new("office")));
+    thermometer.subscribe(&lobby);
+    thermometer.subscribe(&office);
+
+    thermometer.notify(21);
+    thermometer.notify(23);
+    println!("watching: {}", thermometer.observer_count()); // 2
+
+    // === THE OFFICE DISPLAY IS REMOVED ===
+    // Dropping our Rc frees it — the subject only held a Weak
+    println!("\noffice saw {:?}; unplugging it", office.borrow().readings);
+    drop(office);
+    println!(
+        "registered before next notify: {}",
+        thermometer.observer_count()
+    ); // 2 (stale entry)
+
+    thermometer.notify(19); // only the lobby prints
+    println!("registered after notify: {}", thermometer.observer_count()); // 1 — pruned
+
+    println!("\nlobby saw {:?}", lobby.borrow().readings); // [21, 23, 19]
+}
+
+struct Display {
+    name: &'static str,
+    readings: Vec<i32>,
+}
+
+impl Display {
+    fn new(name: &'static str) -> Self {
+        Display {
+            name,
+            readings: Vec::new(),
+        }
+    }
+}
+
+impl Observer for Display {
+    fn on_notify(&mut self, value: i32) {
+        println!("[{}] {}°C", self.name, value);
+        self.readings.push(value);
+    }
+}
+
+impl Drop for Display {
+    fn drop(&mut self) {
+        println!("[{}] display freed", self.name);
+    }
+}
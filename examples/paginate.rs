@@ -0,0 +1,38 @@
+use rust_journey::paginate::{page_count, paginate};
+
+fn main() {
+    let fruits = [
+        "apple",
+        "banana",
+        "cherry",
+        "date",
+        "elderberry",
+        "fig",
+        "grape",
+        "honeydew",
+        "kiwi",
+        "lemon",
+    ];
+    let per_page = 3;
+
+    // === WALK EVERY PAGE ===
+    let pages = page_count(fruits.len(), per_page);
+    println!(
+        "{} items, {} per page → {} pages",
+        fruits.len(),
+        per_page,
+        pages
+    ); // 4 pages
+    for page in 1..=pages {
+        println!(
+            "  page {}/{}: {:?}",
+            page,
+            pages,
+            paginate(&fruits, page, per_page)
+        );
+    }
+
+    // === ASKING FOR A PAGE THAT ISN'T THERE ===
+    println!("\npage 5: {:?}", paginate(&fruits, 5, per_page)); // []
+    println!("page 0: {:?}", paginate(&fruits, 0, per_page)); // [] — pages start at 1
+}
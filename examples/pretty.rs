@@ -0,0 +1,27 @@
+use rust_journey::pretty::{Node, Pretty};
+
+fn main() {
+    // === A DIRECTORY TREE ===
+    let project = Node::branch(
+        "rust-journey/",
+        vec![
+            Node::leaf("Cargo.toml"),
+            Node::branch(
+                "src/",
+                vec![
+                    Node::leaf("main.rs"),
+                    Node::leaf("lib.rs"),
+                    Node::branch("bin/", vec![Node::leaf("tool.rs")]),
+                ],
+            ),
+            Node::branch("examples/", vec![Node::leaf("pretty.rs")]),
+            Node::branch("target/", vec![]),
+        ],
+    );
+    println!("{}", project.pretty(0));
+
+    // === START DEEPER ===
+    // A subtree rendered at level 2, e.g. to embed in other output
+    println!("\nsrc only, nested:");
+    println!("{}", project.children[1].pretty(2));
+}
@@ -0,0 +1,23 @@
+use rust_journey::range_builder::float_range;
+
+fn main() {
+    // === INTEGER RANGES HAVE step_by, FLOATS DON'T ===
+    let ints: Vec<i32> = (0..10).step_by(3).collect();
+    println!("ints:   {:?}", ints); // [0, 3, 6, 9]
+
+    let quarters = float_range(0.0, 1.0, 0.25).unwrap();
+    println!("floats: {:?}", quarters); // [0.0, 0.25, 0.5, 0.75]
+
+    // === COUNTING DOWN ===
+    println!("down:   {:?}", float_range(1.0, 0.0, -0.25).unwrap()); // [1.0, 0.75, 0.5, 0.25]
+
+    // === A SMALL TABLE ===
+    println!("\n  x    x²");
+    for x in float_range(0.0, 2.0, 0.5).unwrap() {
+        println!("{:>4}  {:>4}", x, x * x);
+    }
+
+    // === BAD STEPS ARE ERRORS, NOT INFINITE LOOPS ===
+    println!("\n{:?}", float_range(0.0, 1.0, 0.0));
+    println!("{:?}", float_range(0.0, 1.0, -0.5));
+}
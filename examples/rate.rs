@@ -0,0 +1,38 @@
+use rust_journey::rate::RateCounter;
+use std::time::{Duration, Instant};
+
+fn main() {
+    // === SIMULATED TRAFFIC ===
+    // Instead of sleeping, build timestamps as offsets from a start Instant
+    let start = Instant::now();
+    let at = |ms: u64| start + Duration::from_millis(ms);
+
+    // Quiet, then a burst around t=3s, then quiet again
+    let mut arrivals: Vec<u64> = vec![0, 700, 1500];
+    arrivals.extend((0..20).map(|i| 3000 + i * 25)); // 20 requests in half a second
+    arrivals.extend([4200, 6800]);
+
+    let mut counter = RateCounter::new(Duration::from_secs(2));
+    let mut next = arrivals.iter().peekable();
+
+    // Sample the rate every half second, recording whatever arrived since
+    println!("  time   last 2s   rate/s");
+    for tick in (0..=8000).step_by(500) {
+        while let Some(&&ms) = next.peek() {
+            if ms > tick {
+                break;
+            }
+            counter.record(at(ms));
+            next.next();
+        }
+        let now = at(tick);
+        let bar = "#".repeat(counter.count(now));
+        println!(
+            "{:>5.1}s  {:>7}  {:>7.1}  {}",
+            tick as f64 / 1000.0,
+            counter.count(now),
+            counter.rate_per_sec(now),
+            bar
+        );
+    }
+}
@@ -0,0 +1,27 @@
+use rust_journey::ringbuf::RingBuffer;
+
+fn main() {
+    // === OVERWRITE: SIZE 3, FED 5 ITEMS ===
+    let mut recent = RingBuffer::new(3);
+    for i in 1..=5 {
+        recent.push(i);
+        println!("pushed {} → len {}, full: {}", i, recent.len(), recent.is_full());
+    }
+
+    // Only the last three survive, oldest first
+    print!("draining: ");
+    while let Some(item) = recent.pop() {
+        print!("{} ", item); // 3 4 5
+    }
+    println!();
+
+    // === PRACTICAL: KEEP THE LAST N LOG LINES ===
+    let mut log = RingBuffer::new(2);
+    for line in ["boot", "connect", "error: timeout", "retry"] {
+        log.push(line);
+    }
+    println!("last {} lines:", log.capacity());
+    while let Some(line) = log.pop() {
+        println!("  {}", line);
+    }
+}
@@ -0,0 +1,39 @@
+use rust_journey::rolling_sum::rolling_sums;
+use std::time::Instant;
+
+fn main() {
+    // === WEEKLY TOTALS FROM DAILY STEPS ===
+    let steps = [
+        8_000, 12_500, 4_300, 9_900, 11_000, 15_200, 3_100, 7_800, 10_400,
+    ];
+    let weekly = rolling_sums(&steps, 7);
+    for (day, total) in weekly.iter().enumerate() {
+        println!("days {}–{}: {:>6} steps", day + 1, day + 7, total);
+    }
+
+    // === SLIDING vs RECOMPUTING ===
+    // Same answers; the naive version re-adds every window from scratch
+    let data: Vec<i64> = (0..50_000).map(|i| (i * 37 % 101) - 50).collect();
+    let window = 1_000;
+
+    let start = Instant::now();
+    let fast = rolling_sums(&data, window);
+    let fast_time = start.elapsed();
+
+    let start = Instant::now();
+    let slow: Vec<i64> = data.windows(window).map(|w| w.iter().sum()).collect();
+    let slow_time = start.elapsed();
+
+    println!("\n{} windows of {}:", fast.len(), window);
+    println!(
+        "  sliding:   {:>10.2?}  (~{} additions)",
+        fast_time,
+        data.len() * 2
+    );
+    println!(
+        "  naive:     {:>10.2?}  (~{} additions)",
+        slow_time,
+        slow.len() * window
+    );
+    println!("  same result: {}", fast == slow); // true
+}
@@ -0,0 +1,20 @@
+use rust_journey::running_median::RunningMedian;
+
+fn main() {
+    // === MEDIAN AFTER EACH READING ===
+    // Response times in ms; the 950 spike barely moves the median
+    let stream = [120.0, 80.0, 95.0, 950.0, 110.0, 85.0, 100.0];
+    let mut m = RunningMedian::new();
+
+    println!("value   median   mean");
+    let mut sum = 0.0;
+    for (i, &x) in stream.iter().enumerate() {
+        m.add(x);
+        sum += x;
+        let mean = sum / (i + 1) as f64;
+        println!("{:>5}  {:>7.1}  {:>5.1}", x, m.median().unwrap(), mean);
+    }
+
+    // === NOTHING YET ===
+    println!("\nempty: {:?}", RunningMedian::new().median()); // None
+}
@@ -0,0 +1,21 @@
+use rust_journey::safe_math::*;
+
+fn main() {
+    // === u8::MAX + 1 FOUR WAYS ===
+    let max = u8::MAX;
+    println!("checked:     {:?}", checked_add_u8(max, 1));     // None
+    println!("saturating:  {}", saturating_add_u8(max, 1));    // 255
+    println!("wrapping:    {}", wrapping_add_u8(max, 1));      // 0
+    println!("overflowing: {:?}", overflowing_add_u8(max, 1)); // (0, true)
+
+    // Plain `max + 1` would panic in a debug build:
+    // "attempt to add with overflow"
+
+    // === SIGNED INTEGERS ===
+    println!("\ni32::MAX wrapping + 1: {}", wrapping_add_i32(i32::MAX, 1)); // i32::MIN
+    println!("i32::MIN saturating - 1: {}", saturating_add_i32(i32::MIN, -1));
+
+    // === SUMMING SAFELY ===
+    println!("\nsum [1, 2, 3]: {:?}", sum_checked(&[1, 2, 3]));      // Some(6)
+    println!("sum [MAX, 1]: {:?}", sum_checked(&[i64::MAX, 1]));     // None
+}
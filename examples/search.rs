@@ -0,0 +1,23 @@
+use rust_journey::search::binary_search;
+
+fn main() {
+    let mut scores = vec![12, 25, 37, 48, 59, 63, 71];
+
+    // === FOUND ===
+    println!("48 → {:?}", binary_search(&scores, &48)); // Ok(3)
+
+    // === NOT FOUND: WHERE IT WOULD GO ===
+    println!("50 → {:?}", binary_search(&scores, &50)); // Err(4)
+    println!("1  → {:?}", binary_search(&scores, &1));  // Err(0)
+    println!("99 → {:?}", binary_search(&scores, &99)); // Err(7)
+
+    // The Err index is exactly where insert keeps the vec sorted
+    if let Err(pos) = binary_search(&scores, &50) {
+        scores.insert(pos, 50);
+    }
+    println!("after insert: {:?}", scores);
+
+    // === WORKS FOR ANY Ord TYPE ===
+    let names = ["alice", "bob", "carol", "dave"];
+    println!("carol → {:?}", binary_search(&names, &"carol")); // Ok(2)
+}
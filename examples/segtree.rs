@@ -0,0 +1,25 @@
+use rust_journey::segtree::SegmentTree;
+
+fn main() {
+    // Daily sales figures
+    let sales = [120, 80, 150, 90, 200, 60, 110];
+    let mut tree = SegmentTree::new(&sales);
+
+    // === RANGE QUERIES ===
+    println!("days 0..7 total: {}", tree.query(0, 7)); // 810
+    println!("days 2..5 total: {}", tree.query(2, 5)); // 440
+    println!("day 4 only:      {}", tree.query(4, 5)); // 200
+
+    // === POINT UPDATE, THEN QUERY AGAIN ===
+    // A refund comes in for day 4
+    tree.update(4, 150);
+    println!("\nafter correcting day 4 to 150:");
+    println!("days 2..5 total: {}", tree.query(2, 5)); // 390
+    println!("days 0..7 total: {}", tree.query(0, 7)); // 760
+
+    // === ROLLING 3-DAY WINDOWS ===
+    println!("\n3-day windows:");
+    for start in 0..=tree.len() - 3 {
+        println!("  days {}..{}: {}", start, start + 3, tree.query(start, start + 3));
+    }
+}
@@ -0,0 +1,23 @@
+use rust_journey::shapes::{total_area, Circle, Rectangle, Shape, Triangle};
+
+fn main() {
+    // === A GALLERY OF DIFFERENT TYPES IN ONE VEC ===
+    let gallery: Vec<Box<dyn Shape>> = vec![
+        Box::new(Circle { radius: 1.5 }),
+        Box::new(Rectangle { width: 4.0, height: 2.5 }),
+        Box::new(Triangle { a: 3.0, b: 4.0, c: 5.0 }),
+        Box::new(Triangle { a: 1.0, b: 2.0, c: 3.0 }), // degenerate: flat line
+    ];
+
+    // === REPORT ===
+    println!("{:<10} {:>8} {:>10}", "shape", "area", "perimeter");
+    for shape in &gallery {
+        println!("{:<10} {:>8.2} {:>10.2}", shape.name(), shape.area(), shape.perimeter());
+    }
+    println!("total area: {:.2}", total_area(&gallery));
+
+    // === FINDING THE LARGEST ===
+    if let Some(biggest) = gallery.iter().max_by(|a, b| a.area().total_cmp(&b.area())) {
+        println!("largest: {} ({:.2})", biggest.name(), biggest.area());
+    }
+}
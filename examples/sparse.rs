@@ -0,0 +1,24 @@
+use rust_journey::sparse::SparseVec;
+
+fn main() {
+    // === A HUGE VECTOR THAT COSTS ALMOST NOTHING ===
+    // A Vec<f64> of this length would allocate 8 MB of zeros
+    let mut a = SparseVec::new(1_000_000);
+    a.set(10, 1.5);
+    a.set(500_000, 2.0);
+    a.set(999_999, -3.0);
+    println!("len: {}, stored: {}", a.len(), a.non_zero_count()); // 1000000, 3
+
+    // Absent entries read as zero
+    println!("a[10] = {}, a[11] = {}", a.get(10), a.get(11));
+
+    // === SETTING TO ZERO FREES THE SLOT ===
+    a.set(10, 0.0);
+    println!("after clearing a[10], stored: {}", a.non_zero_count()); // 2
+
+    // === DOT PRODUCT ===
+    let mut b = SparseVec::new(1_000_000);
+    b.set(500_000, 4.0);
+    b.set(999_999, 1.0);
+    println!("a · b = {}", a.dot(&b)); // 2*4 + (-3)*1 = 5
+}
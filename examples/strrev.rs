@@ -0,0 +1,20 @@
+use rust_journey::strrev::reverse_chars;
+
+fn main() {
+    // === REVERSING BY CHAR ===
+    let s = "héllo🦀";
+    println!("{} → {}", s, reverse_chars(s)); // 🦀olléh
+
+    // === WHY NOT BYTES? ===
+    let mut bytes = s.as_bytes().to_vec();
+    bytes.reverse();
+    match String::from_utf8(bytes) {
+        Ok(text) => println!("byte reversal: {}", text),
+        Err(e) => println!("byte reversal: invalid UTF-8 ({})", e),
+    }
+
+    // === GRAPHEME CAVEAT ===
+    // 🇯🇵 is two chars, so reversing turns it into 🇵🇯 (a different flag!)
+    let flag = "🇯🇵";
+    println!("flag chars: {}, reversed: {}", flag.chars().count(), reverse_chars(flag));
+}
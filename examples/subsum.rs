@@ -0,0 +1,27 @@
+use rust_journey::subsum::{max_subarray, two_sum};
+
+fn main() {
+    // === TWO SUM: WHICH TWO ITEMS FIT THE BUDGET EXACTLY? ===
+    let prices = [15, 40, 25, 60, 35];
+    let budget = 75;
+    match two_sum(&prices, budget) {
+        Some((i, j)) => println!(
+            "items {} and {} cost {} + {} = {}",
+            i, j, prices[i], prices[j], budget
+        ),
+        None => println!("no pair costs exactly {}", budget),
+    }
+    println!("pair for 1000: {:?}", two_sum(&prices, 1000)); // None
+
+    // === MAX SUBARRAY: BEST STRETCH OF DAILY PROFIT/LOSS ===
+    let daily = [-3, 5, -2, 6, -9, 4, 1, -1];
+    println!("\ndaily: {:?}", daily);
+    println!("best consecutive run earns {:?}", max_subarray(&daily)); // Some(9): 5 - 2 + 6
+
+    // Every day a loss: the best "run" is the single mildest day
+    let bad_week = [-7, -2, -9, -4];
+    println!("bad week best: {:?}", max_subarray(&bad_week)); // Some(-2)
+
+    // No days at all: nothing to sum
+    println!("no days: {:?}", max_subarray(&[])); // None
+}
@@ -0,0 +1,25 @@
+use rust_journey::template::render;
+use std::collections::HashMap;
+
+fn main() {
+    let mut vars = HashMap::new();
+    vars.insert(String::from("name"), String::from("Alice"));
+    vars.insert(String::from("age"), String::from("30"));
+
+    // === BASIC SUBSTITUTION ===
+    match render("Hello {name}, you are {age}", &vars) {
+        Ok(text) => println!("{}", text), // Hello Alice, you are 30
+        Err(e) => println!("error: {}", e),
+    }
+
+    // === ESCAPED BRACES ===
+    println!("{:?}", render("use {{name}} to insert {name}", &vars));
+
+    // === ERRORS ===
+    for template in ["Hi {nickname}", "Hi {name", "Hi }"] {
+        match render(template, &vars) {
+            Ok(text) => println!("'{}' → {}", template, text),
+            Err(e) => println!("'{}' → error: {}", template, e),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use rust_journey::tokenizer::tokenize;
+
+fn main() {
+    // === A VALID EXPRESSION ===
+    let input = "12 + (3.5 * 4)";
+    match tokenize(input) {
+        Ok(tokens) => {
+            println!("{}", input);
+            for token in tokens {
+                println!("  {:?}", token);
+            }
+        }
+        Err((pos, msg)) => println!("error at {}: {}", pos, msg),
+    }
+
+    // === ERRORS POINT AT THE PROBLEM ===
+    for bad in ["3 + $4", "1.2.3 * 2"] {
+        if let Err((pos, msg)) = tokenize(bad) {
+            println!("\n{}", bad);
+            println!("{}^ {}", " ".repeat(pos), msg);
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use rust_journey::topk::k_largest;
+
+fn main() {
+    // === TOP 3 SCORES ===
+    let scores = [72, 95, 88, 61, 95, 79, 100, 54];
+    println!("scores: {:?}", scores);
+    println!("top 3:  {:?}", k_largest(&scores, 3)); // [100, 95, 95]
+
+    // === ANY Ord TYPE — TUPLES COMPARE FIELD BY FIELD ===
+    // (score, name): highest score wins; on a tie the later name ranks higher
+    let players = [
+        (88, "kim"),
+        (95, "ana"),
+        (95, "zoe"),
+        (70, "lee"),
+        (91, "raj"),
+    ];
+    println!("\npodium:");
+    for (place, (score, name)) in k_largest(&players, 3).iter().enumerate() {
+        println!("  {}. {} ({})", place + 1, name, score);
+    }
+
+    // === ASKING FOR MORE THAN THERE IS ===
+    println!(
+        "\ntop 10 of 3: {:?}",
+        k_largest(&["pear", "fig", "apple"], 10)
+    ); // all, sorted descending
+}
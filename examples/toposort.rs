@@ -0,0 +1,32 @@
+use rust_journey::toposort::toposort;
+
+fn main() {
+    // === ORDERING BUILD TASKS ===
+    let tasks = ["fetch", "configure", "compile", "test", "package", "docs", "release"];
+    // (a, b) = "a must run before b"
+    let deps = [
+        (0, 1), // fetch → configure
+        (1, 2), // configure → compile
+        (2, 3), // compile → test
+        (2, 4), // compile → package
+        (1, 5), // configure → docs
+        (3, 6), // test → release
+        (4, 6), // package → release
+        (5, 6), // docs → release
+    ];
+
+    match toposort(&deps) {
+        Ok(order) => {
+            println!("build order:");
+            for (step, id) in order.iter().enumerate() {
+                println!("  {}. {}", step + 1, tasks[*id as usize]);
+            }
+        }
+        Err(e) => println!("error: {}", e),
+    }
+
+    // === A CYCLE CAN'T BE ORDERED ===
+    // test needs package, package needs test
+    let circular = [(0, 1), (1, 2), (2, 3), (3, 4), (4, 3)];
+    println!("\ncircular: {:?}", toposort(&circular));
+}
@@ -0,0 +1,54 @@
+use rust_journey::transitions::StateMachine;
+
+fn main() {
+    // === THE RULES FOR A DOOR ===
+    //   Closed --open--> Open       Open   --close--> Closed
+    //   Closed --lock--> Locked     Locked --unlock--> Closed
+    let mut door = StateMachine::new(Door::Closed);
+    door.add_transition(Door::Closed, Action::Open, Door::Open);
+    door.add_transition(Door::Open, Action::Close, Door::Closed);
+    door.add_transition(Door::Closed, Action::Lock, Door::Locked);
+    door.add_transition(Door::Locked, Action::Unlock, Door::Closed);
+
+    // === A NIGHT AT HOME ===
+    let actions = [
+        Action::Open,
+        Action::Lock, // can't lock an open door
+        Action::Close,
+        Action::Lock,
+        Action::Open, // can't open a locked door
+        Action::Unlock,
+        Action::Open,
+    ];
+
+    println!("start: {:?}", door.current());
+    for action in actions {
+        match door.fire(action) {
+            Ok(state) => println!("{:<7} → {:?}", format!("{:?}", action), state),
+            Err(e) => println!(
+                "{:<7} ✗ {} (still {:?})",
+                format!("{:?}", action),
+                e,
+                door.current()
+            ),
+        }
+    }
+
+    // === ASK BEFORE ACTING ===
+    println!("\ncan lock now? {}", door.can_fire(&Action::Lock)); // false — it's open
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Door {
+    Open,
+    Closed,
+    Locked,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Action {
+    Open,
+    Close,
+    Lock,
+    Unlock,
+}
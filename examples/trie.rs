@@ -0,0 +1,23 @@
+use rust_journey::trie::Trie;
+
+fn main() {
+    // === BUILD A SMALL DICTIONARY ===
+    let mut dict = Trie::new();
+    for word in ["rust", "rusty", "rustacean", "ruby", "run", "runner", "python"] {
+        dict.insert(word);
+    }
+
+    // === MEMBERSHIP ===
+    println!("contains 'rust': {}", dict.contains("rust")); // true
+    println!("contains 'ru':   {}", dict.contains("ru"));   // false — just a prefix
+
+    // === AUTOCOMPLETE ===
+    for prefix in ["rus", "run", "ru", "java"] {
+        let matches = dict.words_with_prefix(prefix);
+        if matches.is_empty() {
+            println!("{:>5}… → (no suggestions)", prefix);
+        } else {
+            println!("{:>5}… → {}", prefix, matches.join(", "));
+        }
+    }
+}
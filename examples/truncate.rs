@@ -0,0 +1,29 @@
+use rust_journey::truncate::truncate_ellipsis;
+
+fn main() {
+    // === FITTING TITLES INTO A 12-CHAR COLUMN ===
+    let titles = [
+        "Short",
+        "Exactly 12 c",
+        "A Much Longer Title Than Fits",
+        "Ünïcödé Ëvërÿwhërë",
+        "日本語のタイトルです",
+        "🦀 Rust 🦀 Rust 🦀",
+    ];
+    // (CJK characters are drawn double-width by most terminals, so that row
+    // looks wider even though it's 10 chars — chars aren't display columns)
+    for title in titles {
+        println!("|{:<12}| ← {}", truncate_ellipsis(title, 12), title);
+    }
+
+    // === WHY NOT JUST SLICE? ===
+    // Byte slicing counts bytes: &"Ünïcödé"[..4] panics, because byte 4 is
+    // in the middle of 'ï' (Ü = bytes 0-1, n = 2, ï = 3-4)
+    let s = "Ünïcödé";
+    println!("\n{:?}: {} chars, {} bytes", s, s.chars().count(), s.len());
+    println!("is byte 4 a char boundary? {}", s.is_char_boundary(4)); // false
+
+    // === EDGE CASES ===
+    println!("\nlimit 1: {:?}", truncate_ellipsis("abc", 1)); // "…"
+    println!("limit 0: {:?}", truncate_ellipsis("abc", 0)); // ""
+}
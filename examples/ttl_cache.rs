@@ -0,0 +1,33 @@
+use rust_journey::ttl_cache::TtlCache;
+use std::time::{Duration, Instant};
+
+fn main() {
+    // Simulated clock: "t=N" is N seconds after start
+    let start = Instant::now();
+    let t = |s: u64| start + Duration::from_secs(s);
+
+    // === SESSION TOKENS THAT LAST 30 SECONDS ===
+    let mut sessions = TtlCache::new(Duration::from_secs(30));
+    sessions.insert("alice", "token-a1", t(0));
+    sessions.insert("bob", "token-b1", t(10));
+
+    for now in [5, 25, 35, 45] {
+        println!(
+            "t={:>2}  alice: {:<18} bob: {:?}",
+            now,
+            format!("{:?}", sessions.get(&"alice", t(now))),
+            sessions.get(&"bob", t(now))
+        );
+    }
+    // alice expires at t=30, bob at t=40
+
+    // === ACTIVITY REFRESHES A SESSION ===
+    println!("\nt=50  alice logs in again");
+    sessions.insert("alice", "token-a2", t(50));
+    println!("t=70  alice: {:?}", sessions.get(&"alice", t(70))); // still valid until t=80
+
+    // === EXPIRED ENTRIES LINGER UNTIL PURGED ===
+    println!("\nstored before purge: {}", sessions.len()); // 2 — bob is expired but present
+    let removed = sessions.purge(t(70));
+    println!("purged {}, stored now: {}", removed, sessions.len()); // 1, 1
+}
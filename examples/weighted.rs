@@ -0,0 +1,48 @@
+use rust_journey::weighted::{total_weight, weighted_pick};
+use std::collections::BTreeMap;
+
+fn main() {
+    // === A LOOT TABLE ===
+    let loot = [
+        ("copper coin", 60.0),
+        ("health potion", 25.0),
+        ("silver ring", 10.0),
+        ("dragon scale", 5.0),
+    ];
+    let total = total_weight(&loot);
+
+    // === SPECIFIC ROLLS ===
+    for roll in [0.0, 59.9, 60.0, 97.5] {
+        println!("roll {:>5.1} → {:?}", roll, weighted_pick(&loot, roll));
+    }
+
+    // === 10,000 RANDOM DROPS ===
+    // The caller owns the randomness — here a tiny xorshift generator
+    // with a fixed seed, so the output is the same every run
+    let mut rng = XorShift(0x2545_F491_4F6C_DD1D);
+    let mut tally: BTreeMap<&str, u32> = BTreeMap::new();
+    for _ in 0..10_000 {
+        let roll = rng.next_f64() * total;
+        if let Some(item) = weighted_pick(&loot, roll) {
+            *tally.entry(item).or_insert(0) += 1;
+        }
+    }
+    println!();
+    for (item, weight) in &loot {
+        let got = tally.get(item).copied().unwrap_or(0);
+        println!("{:<14} expected {:>4.1}%  got {:>5.2}%", item, weight / total * 100.0, got as f64 / 100.0);
+    }
+}
+
+// Minimal pseudo-random generator — fine for a demo, not for anything serious
+struct XorShift(u64);
+
+impl XorShift {
+    // A float in [0, 1)
+    fn next_f64(&mut self) -> f64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 >> 11) as f64 / (1u64 << 53) as f64
+    }
+}
@@ -0,0 +1,15 @@
+use rust_journey::window_max::sliding_max;
+
+fn main() {
+    // === THE CLASSIC EXAMPLE ===
+    let data = [1, 3, -1, -3, 5, 3, 6, 7];
+    let k = 3;
+    println!("data: {:?}, k = {}", data, k);
+    for (window, max) in data.windows(k).zip(sliding_max(&data, k)) {
+        println!("  {:?} → max {}", window, max);
+    }
+
+    // === PEAK TEMPERATURE OVER EACH 7-DAY STRETCH ===
+    let temps = [18, 21, 19, 25, 23, 22, 20, 17, 24, 26, 21, 19];
+    println!("\nweekly highs: {:?}", sliding_max(&temps, 7));
+}
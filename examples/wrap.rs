@@ -0,0 +1,18 @@
+use rust_journey::wrap::wrap;
+
+fn main() {
+    let paragraph = "Rust is a systems programming language that runs blazingly fast, \
+                     prevents segfaults, and guarantees thread safety.";
+
+    // === WRAP AT 20 COLUMNS ===
+    println!("{}", "-".repeat(20));
+    for line in wrap(paragraph, 20) {
+        println!("{}", line);
+    }
+    println!("{}", "-".repeat(20));
+
+    // === A WORD LONGER THAN THE WIDTH ===
+    for line in wrap("see https://doc.rust-lang.org/book for more", 12) {
+        println!("|{:<12}|", line);
+    }
+}
@@ -0,0 +1,63 @@
+// One abstraction, different meanings per type:
+// numbers add up, strings concatenate.
+//
+// The associated type lets each impl choose its own result type —
+// `&str` items can't be joined into a `&str`, so they produce a String.
+pub trait Accumulate {
+    type Output;
+
+    fn accumulate(items: &[Self]) -> Self::Output
+    where
+        Self: Sized;
+}
+
+// Sum; an empty slice gives 0
+impl Accumulate for i32 {
+    type Output = i32;
+
+    fn accumulate(items: &[i32]) -> i32 {
+        items.iter().sum()
+    }
+}
+
+// Concatenation; an empty slice gives ""
+impl Accumulate for String {
+    type Output = String;
+
+    fn accumulate(items: &[String]) -> String {
+        items.concat()
+    }
+}
+
+impl Accumulate for &str {
+    type Output = String;
+
+    fn accumulate(items: &[&str]) -> String {
+        items.concat()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sums_i32() {
+        assert_eq!(i32::accumulate(&[1, 2, 3]), 6);
+        assert_eq!(i32::accumulate(&[-4, 4]), 0);
+    }
+
+    #[test]
+    fn concatenates_strings() {
+        let words = vec![String::from("foo"), String::from("bar")];
+        assert_eq!(String::accumulate(&words), "foobar");
+        assert_eq!(<&str>::accumulate(&["a", "b", "c"]), "abc");
+    }
+
+    #[test]
+    fn empty_slices() {
+        assert_eq!(i32::accumulate(&[]), 0);
+        assert_eq!(String::accumulate(&[]), "");
+        assert_eq!(<&str>::accumulate(&[]), "");
+    }
+}
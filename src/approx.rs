@@ -0,0 +1,100 @@
+// Floating-point comparison with a tolerance.
+//
+// `0.1 + 0.2 == 0.3` is false because none of those values is exactly
+// representable in binary. Compare "close enough" instead.
+//
+// An absolute epsilon alone breaks down at the extremes: 1e-9 is huge
+// next to 1e-12 and meaningless next to 1e20. So values count as equal
+// if they're within `epsilon` absolutely OR relative to their magnitude.
+pub fn approx_eq(a: f64, b: f64, epsilon: f64) -> bool {
+    // Handles exact matches; equal infinities only match each other here
+    if a == b {
+        return true;
+    }
+    let diff = (a - b).abs();
+    // An infinite (or NaN) operand makes diff inf/NaN, and inf <= inf
+    // below would wrongly pass
+    if !diff.is_finite() {
+        return false;
+    }
+    if diff <= epsilon {
+        return true;
+    }
+    let largest = a.abs().max(b.abs());
+    diff <= largest * epsilon
+}
+
+// assert_approx!(a, b)          — uses an epsilon of 1e-9
+// assert_approx!(a, b, epsilon) — custom tolerance
+#[macro_export]
+macro_rules! assert_approx {
+    ($a:expr, $b:expr) => {
+        $crate::assert_approx!($a, $b, 1e-9)
+    };
+    ($a:expr, $b:expr, $eps:expr) => {{
+        let (a, b, eps): (f64, f64, f64) = ($a, $b, $eps);
+        assert!(
+            $crate::approx::approx_eq(a, b, eps),
+            "assertion failed: {} ≈ {} (epsilon {})",
+            a,
+            b,
+            eps
+        );
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exactly_equal() {
+        assert!(approx_eq(1.5, 1.5, 0.0));
+        assert!(approx_eq(f64::INFINITY, f64::INFINITY, 1e-9));
+    }
+
+    #[test]
+    fn within_epsilon() {
+        assert!(approx_eq(0.1 + 0.2, 0.3, 1e-9));
+        assert_approx!(0.1 + 0.2, 0.3);
+    }
+
+    #[test]
+    fn just_outside_epsilon() {
+        assert!(!approx_eq(1.0, 1.1, 0.01));
+        assert!(!approx_eq(0.0, 1e-6, 1e-9));
+    }
+
+    #[test]
+    fn large_magnitudes_use_relative() {
+        // Absolute difference is 1000, but relative to 1e20 that's tiny
+        assert!(approx_eq(1e20, 1e20 + 1000.0, 1e-9));
+        assert!(!approx_eq(1e20, 1.1e20, 1e-9));
+    }
+
+    #[test]
+    fn small_magnitudes() {
+        // Both tiny and far apart relatively, but within absolute epsilon
+        assert!(approx_eq(1e-12, 2e-12, 1e-9));
+        // With a tighter epsilon the relative check catches the difference
+        assert!(!approx_eq(1e-12, 2e-12, 1e-15));
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed")]
+    fn macro_panics_when_far_apart() {
+        assert_approx!(1.0, 2.0);
+    }
+
+    #[test]
+    fn infinity_only_matches_itself() {
+        assert!(!approx_eq(f64::INFINITY, 1.0, 1e-9));
+        assert!(!approx_eq(1.0, f64::NEG_INFINITY, 1e-9));
+        assert!(!approx_eq(f64::INFINITY, f64::NEG_INFINITY, 1e-9));
+    }
+
+    #[test]
+    fn nan_is_never_equal() {
+        assert!(!approx_eq(f64::NAN, f64::NAN, 1.0));
+    }
+}
@@ -0,0 +1,83 @@
+// Index of the smallest/largest element, rather than the element itself —
+// useful when the position matters (which day, which player, which row).
+//
+// All three return None for an empty slice and the *first* index on ties.
+// They only need PartialOrd, so floats work; a NaN never compares less or
+// greater than anything, so it's never picked unless it's the first item.
+
+pub fn argmin<T: PartialOrd>(data: &[T]) -> Option<usize> {
+    best_index(data, |candidate, best| candidate < best)
+}
+
+pub fn argmax<T: PartialOrd>(data: &[T]) -> Option<usize> {
+    best_index(data, |candidate, best| candidate > best)
+}
+
+// argmin by a derived key, e.g. the index of the shortest string:
+// arg_by_key(&words, |w| w.len())
+pub fn arg_by_key<T, K: PartialOrd, F: Fn(&T) -> K>(data: &[T], key: F) -> Option<usize> {
+    let keys: Vec<K> = data.iter().map(key).collect();
+    argmin(&keys)
+}
+
+// Shared scan: replace the current best only when strictly better,
+// which is what keeps the first index on ties
+fn best_index<T>(data: &[T], better: impl Fn(&T, &T) -> bool) -> Option<usize> {
+    if data.is_empty() {
+        return None;
+    }
+    let mut best = 0;
+    for (i, item) in data.iter().enumerate().skip(1) {
+        if better(item, &data[best]) {
+            best = i;
+        }
+    }
+    Some(best)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clear_min_and_max() {
+        let data = [4, 2, 9, 7];
+        assert_eq!(argmin(&data), Some(1));
+        assert_eq!(argmax(&data), Some(2));
+    }
+
+    #[test]
+    fn ties_return_first() {
+        let data = [3, 1, 5, 1, 5];
+        assert_eq!(argmin(&data), Some(1));
+        assert_eq!(argmax(&data), Some(2));
+    }
+
+    #[test]
+    fn single_element() {
+        assert_eq!(argmin(&[42]), Some(0));
+        assert_eq!(argmax(&["only"]), Some(0));
+    }
+
+    #[test]
+    fn empty_is_none() {
+        let empty: [f64; 0] = [];
+        assert_eq!(argmin(&empty), None);
+        assert_eq!(argmax(&empty), None);
+        assert_eq!(arg_by_key(&empty, |x| *x), None);
+    }
+
+    #[test]
+    fn floats() {
+        assert_eq!(argmin(&[0.5, -1.5, 2.0]), Some(1));
+        assert_eq!(argmax(&[0.5, f64::NAN, 2.0]), Some(2));
+    }
+
+    #[test]
+    fn by_derived_key() {
+        let words = ["banana", "fig", "cherry", "kiwi", "date"];
+        assert_eq!(arg_by_key(&words, |w| w.len()), Some(1));
+        // Largest via a negated key
+        assert_eq!(arg_by_key(&words, |w| -(w.len() as i32)), Some(0));
+    }
+}
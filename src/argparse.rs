@@ -0,0 +1,239 @@
+use std::collections::HashMap;
+use std::fmt;
+
+// A tiny command-line parser. Register the flags you accept, each with a
+// type and a default, then parse argv into typed values:
+//
+//   let parser = ArgParser::new()
+//       .bool_flag("--verbose")
+//       .string_flag("--name", "world")
+//       .int_flag("--count", 1);
+//   let args = parser.parse(&argv)?;
+//   args.int("--count")  // Some(1) unless overridden
+//
+// Bool flags take no value — present means true. String and int flags
+// take the next argument as their value. Anything not starting with "--"
+// is collected as a positional argument.
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Bool(bool),
+    Str(String),
+    Int(i64),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ArgError {
+    UnknownFlag(String),
+    MissingValue(String),
+    BadInt { flag: String, value: String },
+}
+
+impl fmt::Display for ArgError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ArgError::UnknownFlag(flag) => write!(f, "unknown flag '{}'", flag),
+            ArgError::MissingValue(flag) => write!(f, "flag '{}' needs a value", flag),
+            ArgError::BadInt { flag, value } => {
+                write!(f, "flag '{}' expects a number, got '{}'", flag, value)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ArgError {}
+
+// Flag name → default value. The default's variant doubles as the flag's type.
+#[derive(Debug, Default)]
+pub struct ArgParser {
+    flags: HashMap<String, Value>,
+}
+
+// The parse result: every registered flag has a value (given or default)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedArgs {
+    values: HashMap<String, Value>,
+    pub positional: Vec<String>,
+}
+
+impl ArgParser {
+    pub fn new() -> Self {
+        ArgParser::default()
+    }
+
+    // Defaults to false
+    pub fn bool_flag(mut self, name: &str) -> Self {
+        self.flags.insert(name.to_string(), Value::Bool(false));
+        self
+    }
+
+    pub fn string_flag(mut self, name: &str, default: &str) -> Self {
+        self.flags
+            .insert(name.to_string(), Value::Str(default.to_string()));
+        self
+    }
+
+    pub fn int_flag(mut self, name: &str, default: i64) -> Self {
+        self.flags.insert(name.to_string(), Value::Int(default));
+        self
+    }
+
+    // `args` should not include the program name (skip argv[0] first).
+    // Stops at the first problem. A later repeat of a flag overrides an
+    // earlier one.
+    pub fn parse(&self, args: &[String]) -> Result<ParsedArgs, ArgError> {
+        let mut values = self.flags.clone();
+        let mut positional = Vec::new();
+        let mut iter = args.iter();
+
+        while let Some(arg) = iter.next() {
+            if !arg.starts_with("--") {
+                positional.push(arg.clone());
+                continue;
+            }
+            let kind = self
+                .flags
+                .get(arg)
+                .ok_or_else(|| ArgError::UnknownFlag(arg.clone()))?;
+            let value = match kind {
+                Value::Bool(_) => Value::Bool(true),
+                Value::Str(_) => Value::Str(take_value(arg, &mut iter)?.clone()),
+                Value::Int(_) => {
+                    let raw = take_value(arg, &mut iter)?;
+                    let n = raw.parse().map_err(|_| ArgError::BadInt {
+                        flag: arg.clone(),
+                        value: raw.clone(),
+                    })?;
+                    Value::Int(n)
+                }
+            };
+            values.insert(arg.clone(), value);
+        }
+
+        Ok(ParsedArgs { values, positional })
+    }
+}
+
+// The argument after a flag is its value — unless there isn't one, or
+// it's another flag (so "--name --verbose" doesn't name you "--verbose")
+fn take_value<'a>(
+    flag: &str,
+    iter: &mut impl Iterator<Item = &'a String>,
+) -> Result<&'a String, ArgError> {
+    match iter.next() {
+        Some(value) if !value.starts_with("--") => Ok(value),
+        _ => Err(ArgError::MissingValue(flag.to_string())),
+    }
+}
+
+impl ParsedArgs {
+    // Each getter returns None if the flag wasn't registered with that type
+    pub fn flag(&self, name: &str) -> Option<bool> {
+        match self.values.get(name)? {
+            Value::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub fn string(&self, name: &str) -> Option<&str> {
+        match self.values.get(name)? {
+            Value::Str(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn int(&self, name: &str) -> Option<i64> {
+        match self.values.get(name)? {
+            Value::Int(n) => Some(*n),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parser() -> ArgParser {
+        ArgParser::new()
+            .bool_flag("--verbose")
+            .string_flag("--name", "world")
+            .int_flag("--count", 1)
+    }
+
+    fn argv(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn mix_of_flags() {
+        let args = parser()
+            .parse(&argv("--name ferris input.txt --count 3 --verbose"))
+            .unwrap();
+        assert_eq!(args.string("--name"), Some("ferris"));
+        assert_eq!(args.int("--count"), Some(3));
+        assert_eq!(args.flag("--verbose"), Some(true));
+        assert_eq!(args.positional, vec!["input.txt"]);
+    }
+
+    #[test]
+    fn bool_flag_presence() {
+        assert_eq!(
+            parser()
+                .parse(&argv("--verbose"))
+                .unwrap()
+                .flag("--verbose"),
+            Some(true)
+        );
+        assert_eq!(
+            parser().parse(&argv("")).unwrap().flag("--verbose"),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn bad_int_errors() {
+        assert_eq!(
+            parser().parse(&argv("--count lots")),
+            Err(ArgError::BadInt {
+                flag: String::from("--count"),
+                value: String::from("lots")
+            })
+        );
+    }
+
+    #[test]
+    fn unknown_flag_errors() {
+        assert_eq!(
+            parser().parse(&argv("--colour red")),
+            Err(ArgError::UnknownFlag(String::from("--colour")))
+        );
+    }
+
+    #[test]
+    fn missing_value_errors() {
+        assert_eq!(
+            parser().parse(&argv("--name")),
+            Err(ArgError::MissingValue(String::from("--name")))
+        );
+        assert_eq!(
+            parser().parse(&argv("--count --verbose")),
+            Err(ArgError::MissingValue(String::from("--count")))
+        );
+    }
+
+    #[test]
+    fn defaults_for_unspecified() {
+        let args = parser().parse(&[]).unwrap();
+        assert_eq!(args.string("--name"), Some("world"));
+        assert_eq!(args.int("--count"), Some(1));
+        assert!(args.positional.is_empty());
+    }
+
+    #[test]
+    fn getters_check_type() {
+        let args = parser().parse(&[]).unwrap();
+        assert_eq!(args.int("--name"), None);
+        assert_eq!(args.flag("--unregistered"), None);
+    }
+}
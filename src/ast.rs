@@ -0,0 +1,172 @@
+// Second half of the calculator: turns the tokenizer's flat token list
+// into a tree, then evaluates the tree.
+//
+//   "2 + 3 * 4"  →  BinOp(Num(2), Add, BinOp(Num(3), Mul, Num(4)))  →  14
+//
+// The parser is recursive descent, one function per precedence level:
+//
+//   expr   = term   (('+' | '-') term)*
+//   term   = factor (('*' | '/') factor)*
+//   factor = number | '(' expr ')'
+//
+// Lower-precedence levels call the higher ones, so * and / bind tighter
+// than + and -, and the loops make each level left-associative
+// (8 - 2 - 1 is (8 - 2) - 1). There's no unary minus: "-3" is an error.
+
+use crate::tokenizer::{Token, tokenize};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+// Box is needed because Expr contains itself — without it the enum
+// would have infinite size
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Num(f64),
+    BinOp(Box<Expr>, Op, Box<Expr>),
+}
+
+pub fn parse(input: &str) -> Result<Expr, String> {
+    let tokens = tokenize(input).map_err(|(pos, msg)| format!("at {}: {}", pos, msg))?;
+    let mut parser = Parser { tokens, pos: 0 };
+
+    let expr = parser.expr()?;
+    // Everything must be consumed — "1 2" shouldn't quietly parse as 1
+    if let Some(token) = parser.peek() {
+        return Err(format!("unexpected {:?} after expression", token));
+    }
+    Ok(expr)
+}
+
+// Follows the tree bottom-up. Division by zero follows f64 rules
+// (inf or NaN) rather than erroring.
+pub fn eval(expr: &Expr) -> f64 {
+    match expr {
+        Expr::Num(n) => *n,
+        Expr::BinOp(lhs, op, rhs) => {
+            let (a, b) = (eval(lhs), eval(rhs));
+            match op {
+                Op::Add => a + b,
+                Op::Sub => a - b,
+                Op::Mul => a * b,
+                Op::Div => a / b,
+            }
+        }
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn advance(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    fn expr(&mut self) -> Result<Expr, String> {
+        let mut lhs = self.term()?;
+        while let Some(op) = match self.peek() {
+            Some(Token::Plus) => Some(Op::Add),
+            Some(Token::Minus) => Some(Op::Sub),
+            _ => None,
+        } {
+            self.advance();
+            let rhs = self.term()?;
+            lhs = Expr::BinOp(Box::new(lhs), op, Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn term(&mut self) -> Result<Expr, String> {
+        let mut lhs = self.factor()?;
+        while let Some(op) = match self.peek() {
+            Some(Token::Star) => Some(Op::Mul),
+            Some(Token::Slash) => Some(Op::Div),
+            _ => None,
+        } {
+            self.advance();
+            let rhs = self.factor()?;
+            lhs = Expr::BinOp(Box::new(lhs), op, Box::new(rhs));
+        }
+        Ok(lhs)
+    }
+
+    fn factor(&mut self) -> Result<Expr, String> {
+        match self.advance() {
+            Some(Token::Number(n)) => Ok(Expr::Num(n)),
+            Some(Token::LParen) => {
+                let inner = self.expr()?;
+                match self.advance() {
+                    Some(Token::RParen) => Ok(inner),
+                    Some(other) => Err(format!("expected ')' but found {:?}", other)),
+                    None => Err(String::from("missing ')' at end of input")),
+                }
+            }
+            Some(other) => Err(format!("expected a number or '(' but found {:?}", other)),
+            None => Err(String::from("unexpected end of input")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn num(n: f64) -> Box<Expr> {
+        Box::new(Expr::Num(n))
+    }
+
+    #[test]
+    fn simple_addition() {
+        let tree = parse("2+3").unwrap();
+        assert_eq!(tree, Expr::BinOp(num(2.0), Op::Add, num(3.0)));
+        assert_eq!(eval(&tree), 5.0);
+    }
+
+    #[test]
+    fn multiplication_binds_tighter() {
+        let tree = parse("2 + 3 * 4").unwrap();
+        let product = Expr::BinOp(num(3.0), Op::Mul, num(4.0));
+        assert_eq!(tree, Expr::BinOp(num(2.0), Op::Add, Box::new(product)));
+        assert_eq!(eval(&tree), 14.0);
+    }
+
+    #[test]
+    fn nested_parentheses() {
+        assert_eq!(eval(&parse("(2 + 3) * (10 - (1 + 1))").unwrap()), 40.0);
+        assert_eq!(eval(&parse("((((7))))").unwrap()), 7.0);
+    }
+
+    #[test]
+    fn left_associative() {
+        assert_eq!(eval(&parse("8 - 2 - 1").unwrap()), 5.0);
+        assert_eq!(eval(&parse("16 / 4 / 2").unwrap()), 2.0);
+    }
+
+    #[test]
+    fn malformed_input_is_an_error() {
+        assert!(parse("2 +").unwrap_err().contains("end of input"));
+        assert!(parse("(1 + 2").unwrap_err().contains("missing ')'"));
+        assert!(parse("1 2").unwrap_err().contains("after expression"));
+        assert!(parse("* 3").is_err());
+        assert!(parse("").is_err());
+        // Tokenizer errors come through with their position
+        assert_eq!(
+            parse("1 + x").unwrap_err(),
+            "at 4: unexpected character 'x'"
+        );
+    }
+}
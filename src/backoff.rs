@@ -0,0 +1,68 @@
+use std::time::Duration;
+
+// How long to wait between retries.
+// `attempt` counts from 0: delay(0) is the wait before the first retry.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Backoff {
+    // Same wait every time
+    Fixed(Duration),
+    // step, 2×step, 3×step, ...
+    Linear(Duration),
+    // base, 2×base, 4×base, ... but never more than cap
+    Exponential { base: Duration, cap: Duration },
+}
+
+impl Backoff {
+    pub fn delay(&self, attempt: u32) -> Duration {
+        match *self {
+            Backoff::Fixed(d) => d,
+            Backoff::Linear(step) => step.saturating_mul(attempt.saturating_add(1)),
+            Backoff::Exponential { base, cap } => {
+                // 2^attempt overflows u32 past attempt 31 — anything that
+                // big is over the cap anyway
+                let factor = 2u32.checked_pow(attempt).unwrap_or(u32::MAX);
+                base.saturating_mul(factor).min(cap)
+            }
+        }
+    }
+
+    // The first `attempts` delays, handy for logging a retry plan
+    pub fn schedule(&self, attempts: u32) -> Vec<Duration> {
+        (0..attempts).map(|a| self.delay(a)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    #[test]
+    fn fixed_is_constant() {
+        let b = Backoff::Fixed(ms(250));
+        assert_eq!(b.schedule(4), vec![ms(250); 4]);
+    }
+
+    #[test]
+    fn linear_scales_with_attempt() {
+        let b = Backoff::Linear(ms(100));
+        assert_eq!(b.schedule(4), vec![ms(100), ms(200), ms(300), ms(400)]);
+    }
+
+    #[test]
+    fn exponential_doubles() {
+        let b = Backoff::Exponential { base: ms(100), cap: ms(10_000) };
+        assert_eq!(b.schedule(4), vec![ms(100), ms(200), ms(400), ms(800)]);
+    }
+
+    #[test]
+    fn exponential_clamps_at_cap() {
+        let b = Backoff::Exponential { base: ms(100), cap: ms(500) };
+        assert_eq!(b.schedule(5), vec![ms(100), ms(200), ms(400), ms(500), ms(500)]);
+        // Huge attempt numbers don't overflow
+        assert_eq!(b.delay(1_000), ms(500));
+    }
+}
@@ -0,0 +1,131 @@
+// Standard base64 (RFC 4648) with `=` padding.
+//
+// Every 3 input bytes (24 bits) become 4 output chars of 6 bits each:
+//
+//   M        a        n
+//   01001101 01100001 01101110
+//   010011 010110 000101 101110
+//   T      W      F      u
+//
+// A final group of 1 or 2 bytes is zero-padded to fill its last char
+// and marked with `==` or `=`.
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+pub fn encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+
+    for chunk in data.chunks(3) {
+        // Pack up to 3 bytes into the top 24 bits of a u32
+        let b0 = chunk[0] as u32;
+        let b1 = chunk.get(1).copied().unwrap_or(0) as u32;
+        let b2 = chunk.get(2).copied().unwrap_or(0) as u32;
+        let group = (b0 << 16) | (b1 << 8) | b2;
+
+        // Peel off four 6-bit indices
+        let sextet = |shift: u32| ALPHABET[((group >> shift) & 0b11_1111) as usize] as char;
+        out.push(sextet(18));
+        out.push(sextet(12));
+        out.push(if chunk.len() > 1 { sextet(6) } else { '=' });
+        out.push(if chunk.len() > 2 { sextet(0) } else { '=' });
+    }
+
+    out
+}
+
+pub fn decode(s: &str) -> Result<Vec<u8>, String> {
+    let bytes = s.as_bytes();
+    if !bytes.len().is_multiple_of(4) {
+        return Err(format!("length {} is not a multiple of 4", bytes.len()));
+    }
+
+    let mut out = Vec::with_capacity(bytes.len() / 4 * 3);
+    let chunk_count = bytes.len() / 4;
+
+    for (chunk_index, chunk) in bytes.chunks(4).enumerate() {
+        // Padding is only allowed at the very end: "xx==" or "xxx="
+        let is_last = chunk_index + 1 == chunk_count;
+        let padding = chunk.iter().rev().take_while(|&&b| b == b'=').count();
+        if padding > 2 || (padding > 0 && !is_last) {
+            return Err(format!("misplaced padding in group {}", chunk_index));
+        }
+
+        let mut group: u32 = 0;
+        for (i, &b) in chunk[..4 - padding].iter().enumerate() {
+            let value = sextet_value(b).ok_or_else(|| {
+                format!("invalid character '{}' at position {}", b as char, chunk_index * 4 + i)
+            })?;
+            group |= (value as u32) << (18 - 6 * i);
+        }
+
+        out.push((group >> 16) as u8);
+        if padding < 2 {
+            out.push((group >> 8) as u8);
+        }
+        if padding < 1 {
+            out.push(group as u8);
+        }
+    }
+
+    Ok(out)
+}
+
+// Reverse lookup: alphabet char → its 6-bit value
+fn sextet_value(b: u8) -> Option<u8> {
+    match b {
+        b'A'..=b'Z' => Some(b - b'A'),
+        b'a'..=b'z' => Some(b - b'a' + 26),
+        b'0'..=b'9' => Some(b - b'0' + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn three_bytes_no_padding() {
+        assert_eq!(encode(b"Man"), "TWFu");
+        assert_eq!(decode("TWFu"), Ok(b"Man".to_vec()));
+    }
+
+    #[test]
+    fn two_bytes_one_pad() {
+        assert_eq!(encode(b"Ma"), "TWE=");
+        assert_eq!(decode("TWE="), Ok(b"Ma".to_vec()));
+    }
+
+    #[test]
+    fn one_byte_two_pads() {
+        assert_eq!(encode(b"M"), "TQ==");
+        assert_eq!(decode("TQ=="), Ok(b"M".to_vec()));
+    }
+
+    #[test]
+    fn empty_input() {
+        assert_eq!(encode(b""), "");
+        assert_eq!(decode(""), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn round_trip_all_bytes() {
+        let data: Vec<u8> = (0..=255).collect();
+        assert_eq!(decode(&encode(&data)), Ok(data));
+    }
+
+    #[test]
+    fn rejects_invalid_characters() {
+        assert!(decode("TW!u").is_err());
+        assert!(decode("TWFu\n").is_err());
+    }
+
+    #[test]
+    fn rejects_bad_length_and_padding() {
+        assert!(decode("TWF").is_err());
+        assert!(decode("T===").is_err());
+        assert!(decode("TQ==TWFu").is_err());
+    }
+}
@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// One-to-one map you can query from either side.
+// Two HashMaps are kept in sync: every left appears at most once and
+// every right appears at most once.
+#[derive(Debug, Clone)]
+pub struct BiMap<L, R> {
+    left_to_right: HashMap<L, R>,
+    right_to_left: HashMap<R, L>,
+}
+
+impl<L, R> BiMap<L, R>
+where
+    L: Eq + Hash + Clone,
+    R: Eq + Hash + Clone,
+{
+    pub fn new() -> BiMap<L, R> {
+        BiMap { left_to_right: HashMap::new(), right_to_left: HashMap::new() }
+    }
+
+    // Pairs `l` with `r`. Any existing pairing of either `l` or `r` is
+    // removed first, so the map stays one-to-one.
+    pub fn insert(&mut self, l: L, r: R) {
+        self.remove_by_left(&l);
+        self.remove_by_right(&r);
+        self.left_to_right.insert(l.clone(), r.clone());
+        self.right_to_left.insert(r, l);
+    }
+
+    pub fn get_by_left(&self, l: &L) -> Option<&R> {
+        self.left_to_right.get(l)
+    }
+
+    pub fn get_by_right(&self, r: &R) -> Option<&L> {
+        self.right_to_left.get(r)
+    }
+
+    // Removes the pair from both maps, returning the right side
+    pub fn remove_by_left(&mut self, l: &L) -> Option<R> {
+        let r = self.left_to_right.remove(l)?;
+        self.right_to_left.remove(&r);
+        Some(r)
+    }
+
+    // Removes the pair from both maps, returning the left side
+    pub fn remove_by_right(&mut self, r: &R) -> Option<L> {
+        let l = self.right_to_left.remove(r)?;
+        self.left_to_right.remove(&l);
+        Some(l)
+    }
+
+    pub fn len(&self) -> usize {
+        self.left_to_right.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.left_to_right.is_empty()
+    }
+}
+
+impl<L, R> Default for BiMap<L, R>
+where
+    L: Eq + Hash + Clone,
+    R: Eq + Hash + Clone,
+{
+    fn default() -> Self {
+        BiMap::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_lookups() {
+        let mut m = BiMap::new();
+        m.insert("France", "FR");
+        m.insert("Japan", "JP");
+        assert_eq!(m.get_by_left(&"France"), Some(&"FR"));
+        assert_eq!(m.get_by_right(&"JP"), Some(&"Japan"));
+        assert_eq!(m.get_by_left(&"Chile"), None);
+        assert_eq!(m.len(), 2);
+    }
+
+    #[test]
+    fn overwriting_left_cleans_stale_right() {
+        let mut m = BiMap::new();
+        m.insert("Germany", "DE");
+        m.insert("Germany", "GER");
+        assert_eq!(m.get_by_left(&"Germany"), Some(&"GER"));
+        assert_eq!(m.get_by_right(&"DE"), None);
+        assert_eq!(m.len(), 1);
+    }
+
+    #[test]
+    fn overwriting_right_cleans_stale_left() {
+        let mut m = BiMap::new();
+        m.insert("Holland", "NL");
+        m.insert("Netherlands", "NL");
+        assert_eq!(m.get_by_right(&"NL"), Some(&"Netherlands"));
+        assert_eq!(m.get_by_left(&"Holland"), None);
+        assert_eq!(m.len(), 1);
+    }
+
+    #[test]
+    fn remove_clears_both_maps() {
+        let mut m = BiMap::new();
+        m.insert(1, 'a');
+        assert_eq!(m.remove_by_left(&1), Some('a'));
+        assert_eq!(m.get_by_left(&1), None);
+        assert_eq!(m.get_by_right(&'a'), None);
+        assert!(m.is_empty());
+        assert_eq!(m.remove_by_left(&1), None);
+    }
+}
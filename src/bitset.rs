@@ -0,0 +1,184 @@
+// A set of small non-negative integers packed into bits.
+//
+// Bit i lives in word i / 64, at position i % 64 within that word:
+//
+//   words[0]: bits 0..=63     words[1]: bits 64..=127    ...
+//
+// A HashSet<usize> spends a whole hash entry per member; this spends one
+// bit per *possible* member, and set operations run 64 members at a time
+// with a single | or &. The trade-off: memory grows with the largest
+// index, not the number of members.
+#[derive(Debug, Clone, Default)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+const BITS: usize = u64::BITS as usize;
+
+// Which word holds bit i, and the mask selecting it within that word
+fn locate(i: usize) -> (usize, u64) {
+    (i / BITS, 1 << (i % BITS))
+}
+
+impl BitSet {
+    pub fn new() -> Self {
+        BitSet::default()
+    }
+
+    // Grows the word vector as needed
+    pub fn set(&mut self, i: usize) {
+        let (word, mask) = locate(i);
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+        self.words[word] |= mask;
+    }
+
+    pub fn clear(&mut self, i: usize) {
+        let (word, mask) = locate(i);
+        if let Some(w) = self.words.get_mut(word) {
+            *w &= !mask;
+        }
+    }
+
+    // Bits past the end of `words` are simply unset
+    pub fn contains(&self, i: usize) -> bool {
+        let (word, mask) = locate(i);
+        self.words.get(word).is_some_and(|w| w & mask != 0)
+    }
+
+    // popcount per word — a single CPU instruction on most targets
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    // self = self ∪ other
+    pub fn union_with(&mut self, other: &BitSet) {
+        if other.words.len() > self.words.len() {
+            self.words.resize(other.words.len(), 0);
+        }
+        for (mine, theirs) in self.words.iter_mut().zip(&other.words) {
+            *mine |= theirs;
+        }
+    }
+
+    // self = self ∩ other. Words past the end of `other` are all zero
+    // there, so ours get cleared.
+    pub fn intersect_with(&mut self, other: &BitSet) {
+        for (i, mine) in self.words.iter_mut().enumerate() {
+            *mine &= other.words.get(i).copied().unwrap_or(0);
+        }
+    }
+
+    // words with the trailing all-zero words dropped — the part that
+    // actually describes the members
+    fn significant_words(&self) -> &[u64] {
+        let len = self
+            .words
+            .iter()
+            .rposition(|&w| w != 0)
+            .map_or(0, |i| i + 1);
+        &self.words[..len]
+    }
+
+    // Members in ascending order
+    pub fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        self.words.iter().enumerate().flat_map(|(w, &word)| {
+            (0..BITS)
+                .filter(move |bit| word & (1 << bit) != 0)
+                .map(move |bit| w * BITS + bit)
+        })
+    }
+}
+
+// Equal when they hold the same members. A derived impl would compare
+// the raw vectors, so set(5) then clear(5) — which leaves a zero word
+// behind — wouldn't equal a fresh BitSet.
+impl PartialEq for BitSet {
+    fn eq(&self, other: &Self) -> bool {
+        self.significant_words() == other.significant_words()
+    }
+}
+
+impl Eq for BitSet {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set_of(members: &[usize]) -> BitSet {
+        let mut s = BitSet::new();
+        for &m in members {
+            s.set(m);
+        }
+        s
+    }
+
+    #[test]
+    fn set_and_contains_across_word_boundary() {
+        let s = set_of(&[0, 63, 64, 200]);
+        for i in [0, 63, 64, 200] {
+            assert!(s.contains(i), "missing {}", i);
+        }
+        for i in [1, 62, 65, 199, 201, 10_000] {
+            assert!(!s.contains(i), "unexpected {}", i);
+        }
+        assert_eq!(s.words.len(), 4);
+    }
+
+    #[test]
+    fn clear_removes_only_that_bit() {
+        let mut s = set_of(&[5, 63, 64]);
+        s.clear(63);
+        assert!(!s.contains(63));
+        assert!(s.contains(5) && s.contains(64));
+        s.clear(1_000); // never set, beyond the end — no-op
+        assert_eq!(s.count_ones(), 2);
+    }
+
+    #[test]
+    fn counting() {
+        assert_eq!(BitSet::new().count_ones(), 0);
+        assert!(BitSet::new().is_empty());
+        let mut s = set_of(&[1, 2, 3, 64, 128]);
+        s.set(2); // already present
+        assert_eq!(s.count_ones(), 5);
+    }
+
+    #[test]
+    fn union_and_intersection() {
+        let mut a = set_of(&[1, 63, 64]);
+        let b = set_of(&[2, 64, 130]);
+
+        let mut union = a.clone();
+        union.union_with(&b);
+        assert_eq!(union.iter().collect::<Vec<_>>(), vec![1, 2, 63, 64, 130]);
+
+        a.intersect_with(&b);
+        assert_eq!(a.iter().collect::<Vec<_>>(), vec![64]);
+    }
+
+    #[test]
+    fn intersect_with_shorter_set_clears_tail() {
+        let mut a = set_of(&[3, 100, 300]);
+        a.intersect_with(&set_of(&[3]));
+        assert_eq!(a.iter().collect::<Vec<_>>(), vec![3]);
+    }
+
+    #[test]
+    fn equality_ignores_trailing_zero_words() {
+        let mut s = BitSet::new();
+        s.set(5);
+        s.clear(5);
+        assert_eq!(s, BitSet::new());
+
+        let mut a = set_of(&[1, 200]);
+        a.clear(200);
+        assert_eq!(a, set_of(&[1]));
+        assert_ne!(a, set_of(&[2]));
+    }
+}
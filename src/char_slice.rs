@@ -0,0 +1,70 @@
+// Substrings by *character* position instead of byte position.
+//
+// `&s[1..3]` counts bytes, and panics if either end lands inside a
+// multibyte character:
+//
+//   let s = "héllo";      // 'é' is 2 bytes: h=0, é=1..3, l=3, l=4, o=5
+//   &s[0..2]               // PANIC — byte 2 is in the middle of 'é'
+//
+// char_substring(s, 1, 2) means "2 chars starting at char 1" → "él".
+// It returns None rather than panicking when the range doesn't fit.
+
+pub fn char_substring(s: &str, start: usize, len: usize) -> Option<String> {
+    // Byte offset of every char boundary, plus the end of the string —
+    // so a range that ends exactly at the last char is still valid
+    let mut boundaries = s
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(std::iter::once(s.len()));
+
+    let from = boundaries.nth(start)?;
+    let to = if len == 0 {
+        from
+    } else {
+        boundaries.nth(len - 1)?
+    };
+    Some(s[from..to].to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ascii_substring() {
+        assert_eq!(
+            char_substring("hello world", 6, 5),
+            Some(String::from("world"))
+        );
+        assert_eq!(char_substring("hello", 0, 5), Some(String::from("hello")));
+    }
+
+    #[test]
+    fn multibyte_chars() {
+        assert_eq!(char_substring("héllo", 1, 2), Some(String::from("él")));
+        assert_eq!(
+            char_substring("日本語テキスト", 2, 3),
+            Some(String::from("語テキ"))
+        );
+        assert_eq!(char_substring("a🦀b", 1, 1), Some(String::from("🦀")));
+    }
+
+    #[test]
+    fn start_beyond_length() {
+        assert_eq!(char_substring("héllo", 6, 1), None);
+        assert_eq!(char_substring("", 1, 0), None);
+    }
+
+    #[test]
+    fn len_past_end() {
+        assert_eq!(char_substring("héllo", 3, 5), None);
+        assert_eq!(char_substring("héllo", 4, 2), None);
+    }
+
+    #[test]
+    fn empty_ranges() {
+        // Zero chars at the very end is still in range
+        assert_eq!(char_substring("héllo", 5, 0), Some(String::new()));
+        assert_eq!(char_substring("", 0, 0), Some(String::new()));
+    }
+}
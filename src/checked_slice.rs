@@ -0,0 +1,68 @@
+// Slice indexing that reports errors instead of panicking.
+//
+// `v[10]` on a 3-element Vec panics. `v.get(10)` returns None, which
+// says *that* it failed but not *why*. These methods return a Result
+// whose message names the index and the length.
+#[derive(Debug, Clone, Copy)]
+pub struct CheckedSlice<'a, T>(pub &'a [T]);
+
+impl<'a, T> CheckedSlice<'a, T> {
+    pub fn at(&self, i: usize) -> Result<&'a T, String> {
+        self.0
+            .get(i)
+            .ok_or_else(|| format!("index {} out of bounds for length {}", i, self.0.len()))
+    }
+
+    // Half-open range l..r, like &slice[l..r]
+    pub fn range(&self, l: usize, r: usize) -> Result<&'a [T], String> {
+        if l > r {
+            return Err(format!("range start {} is greater than end {}", l, r));
+        }
+        if r > self.0.len() {
+            return Err(format!("range end {} out of bounds for length {}", r, self.0.len()));
+        }
+        Ok(&self.0[l..r])
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DATA: [i32; 4] = [10, 20, 30, 40];
+
+    #[test]
+    fn valid_access() {
+        let s = CheckedSlice(&DATA);
+        assert_eq!(s.at(0), Ok(&10));
+        assert_eq!(s.at(3), Ok(&40));
+        assert_eq!(s.range(1, 3), Ok(&[20, 30][..]));
+        assert_eq!(s.range(2, 2), Ok(&[][..]));
+    }
+
+    #[test]
+    fn index_out_of_bounds_message() {
+        let s = CheckedSlice(&DATA);
+        assert_eq!(s.at(4), Err(String::from("index 4 out of bounds for length 4")));
+    }
+
+    #[test]
+    fn inverted_range() {
+        let s = CheckedSlice(&DATA);
+        assert_eq!(s.range(3, 1), Err(String::from("range start 3 is greater than end 1")));
+    }
+
+    #[test]
+    fn range_past_end() {
+        let s = CheckedSlice(&DATA);
+        assert_eq!(s.range(2, 9), Err(String::from("range end 9 out of bounds for length 4")));
+    }
+}
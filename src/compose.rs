@@ -0,0 +1,43 @@
+// Function composition: build new functions out of existing ones.
+
+// compose(f, g) is a closure computing g(f(x)) — "f, then g"
+pub fn compose<A, B, C>(f: impl Fn(A) -> B, g: impl Fn(B) -> C) -> impl Fn(A) -> C {
+    move |x| g(f(x))
+}
+
+// Runs `input` through each transform in order.
+// Boxed trait objects let one slice hold different closures,
+// as long as they all map T → T.
+pub fn pipe<T>(fns: &[Box<dyn Fn(T) -> T>], input: T) -> T {
+    fns.iter().fold(input, |acc, f| f(acc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compose_applies_f_then_g() {
+        let add_one_then_double = compose(|x: i32| x + 1, |x| x * 2);
+        assert_eq!(add_one_then_double(3), 8);
+    }
+
+    #[test]
+    fn compose_can_change_types() {
+        let len_is_even = compose(|s: &str| s.len(), |n| n % 2 == 0);
+        assert!(len_is_even("ab"));
+        assert!(!len_is_even("abc"));
+    }
+
+    #[test]
+    fn pipe_of_three() {
+        let fns: Vec<Box<dyn Fn(i32) -> i32>> =
+            vec![Box::new(|x| x + 1), Box::new(|x| x * 10), Box::new(|x| x - 3)];
+        assert_eq!(pipe(&fns, 2), 27);
+    }
+
+    #[test]
+    fn empty_pipe_is_identity() {
+        assert_eq!(pipe(&[], String::from("same")), "same");
+    }
+}
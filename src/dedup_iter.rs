@@ -0,0 +1,67 @@
+// An iterator adapter that drops consecutive duplicates:
+// 1, 1, 2, 2, 2, 3, 1  →  1, 2, 3, 1
+//
+// Unlike Vec::dedup it works lazily on any iterator and doesn't need
+// the whole sequence in memory.
+pub struct DedupConsecutive<I: Iterator> {
+    iter: I,
+    // The last item we yielded, to compare the next one against
+    last: Option<I::Item>,
+}
+
+impl<I> Iterator for DedupConsecutive<I>
+where
+    I: Iterator,
+    I::Item: PartialEq + Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        for item in self.iter.by_ref() {
+            if self.last.as_ref() != Some(&item) {
+                self.last = Some(item.clone());
+                return Some(item);
+            }
+        }
+        None
+    }
+}
+
+// Extension trait: adds .dedup_consecutive() to every iterator
+pub trait DedupExt: Iterator + Sized {
+    fn dedup_consecutive(self) -> DedupConsecutive<Self> {
+        DedupConsecutive { iter: self, last: None }
+    }
+}
+
+// Blanket impl — any Iterator gets the method for free
+impl<I: Iterator> DedupExt for I {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn removes_consecutive_duplicates() {
+        let out: Vec<i32> = [1, 1, 2, 2, 2, 3, 1].into_iter().dedup_consecutive().collect();
+        assert_eq!(out, vec![1, 2, 3, 1]);
+    }
+
+    #[test]
+    fn all_same_yields_one() {
+        let out: Vec<char> = "aaaa".chars().dedup_consecutive().collect();
+        assert_eq!(out, vec!['a']);
+    }
+
+    #[test]
+    fn all_distinct_unchanged() {
+        let out: Vec<&i32> = [1, 2, 3, 4].iter().dedup_consecutive().collect();
+        assert_eq!(out, vec![&1, &2, &3, &4]);
+    }
+
+    #[test]
+    fn empty_input() {
+        let out: Vec<i32> = Vec::new().into_iter().dedup_consecutive().collect();
+        assert!(out.is_empty());
+    }
+}
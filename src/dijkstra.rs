@@ -0,0 +1,120 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+// Finds the cheapest route from `start` to `goal` in a weighted graph,
+// returning the total cost and the nodes along the way (both ends included).
+//
+// The graph is an adjacency list: node → [(neighbour, edge cost), ...].
+// Edge costs must be non-negative — Dijkstra relies on a node's cost
+// never improving once it's been popped. A route whose total would
+// overflow u32 is treated as unusable rather than wrapping or panicking.
+//
+// BinaryHeap is a max-heap, so entries are wrapped in Reverse to pop the
+// *cheapest* frontier node first. We don't delete stale heap entries when
+// a better route turns up; we just skip them when they surface.
+pub fn shortest_path(
+    graph: &HashMap<u32, Vec<(u32, u32)>>,
+    start: u32,
+    goal: u32,
+) -> Option<(u32, Vec<u32>)> {
+    let mut best: HashMap<u32, u32> = HashMap::new();
+    // came_from[n] = the node we reached n from on its cheapest route
+    let mut came_from: HashMap<u32, u32> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+
+    best.insert(start, 0);
+    heap.push(Reverse((0, start)));
+
+    while let Some(Reverse((cost, node))) = heap.pop() {
+        if node == goal {
+            return Some((cost, rebuild_path(&came_from, start, goal)));
+        }
+        // A cheaper route to this node was already processed
+        if cost > best[&node] {
+            continue;
+        }
+
+        for &(next, weight) in graph.get(&node).into_iter().flatten() {
+            let Some(new_cost) = cost.checked_add(weight) else {
+                continue;
+            };
+            if best.get(&next).is_none_or(|&old| new_cost < old) {
+                best.insert(next, new_cost);
+                came_from.insert(next, node);
+                heap.push(Reverse((new_cost, next)));
+            }
+        }
+    }
+
+    None
+}
+
+// Walks the came_from links backwards from goal, then flips the result
+fn rebuild_path(came_from: &HashMap<u32, u32>, start: u32, goal: u32) -> Vec<u32> {
+    let mut path = vec![goal];
+    let mut node = goal;
+    while node != start {
+        node = came_from[&node];
+        path.push(node);
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph(edges: &[(u32, u32, u32)]) -> HashMap<u32, Vec<(u32, u32)>> {
+        let mut g: HashMap<u32, Vec<(u32, u32)>> = HashMap::new();
+        for &(from, to, cost) in edges {
+            g.entry(from).or_default().push((to, cost));
+        }
+        g
+    }
+
+    #[test]
+    fn finds_known_shortest_path() {
+        let g = graph(&[
+            (1, 2, 7),
+            (1, 3, 9),
+            (1, 6, 14),
+            (2, 3, 10),
+            (2, 4, 15),
+            (3, 4, 11),
+            (3, 6, 2),
+            (4, 5, 6),
+            (6, 5, 9),
+        ]);
+        assert_eq!(shortest_path(&g, 1, 5), Some((20, vec![1, 3, 6, 5])));
+    }
+
+    #[test]
+    fn unreachable_goal_is_none() {
+        let g = graph(&[(1, 2, 1), (3, 4, 1)]);
+        assert_eq!(shortest_path(&g, 1, 4), None);
+    }
+
+    #[test]
+    fn start_equals_goal_costs_nothing() {
+        let g = graph(&[(1, 2, 5)]);
+        assert_eq!(shortest_path(&g, 1, 1), Some((0, vec![1])));
+    }
+
+    #[test]
+    fn prefers_cheaper_of_two_routes() {
+        // Direct edge is one hop but expensive; the detour is cheaper
+        let g = graph(&[(1, 3, 10), (1, 2, 3), (2, 3, 4)]);
+        assert_eq!(shortest_path(&g, 1, 3), Some((7, vec![1, 2, 3])));
+    }
+
+    #[test]
+    fn overflowing_route_is_skipped() {
+        // 1 → 2 → 3 would cost more than u32::MAX; the direct edge still works
+        let g = graph(&[(1, 2, u32::MAX), (2, 3, 1), (1, 3, 50)]);
+        assert_eq!(shortest_path(&g, 1, 3), Some((50, vec![1, 3])));
+        // With no other route, the goal is unreachable
+        let g = graph(&[(1, 2, u32::MAX), (2, 3, 1)]);
+        assert_eq!(shortest_path(&g, 1, 3), None);
+    }
+}
@@ -0,0 +1,135 @@
+use std::cmp::Ordering;
+
+// Disjoint-set union (union-find) over the elements 0..n.
+//
+// Each set is a tree; its root is the set's representative.
+// - Path compression: find() points every node it visits straight at
+//   the root, flattening the tree for next time
+// - Union by rank: the shorter tree is hung under the taller one, so
+//   trees stay shallow
+// Together they make each operation effectively O(1) amortized.
+#[derive(Debug, Clone)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    // Upper bound on each root's tree height
+    rank: Vec<u8>,
+    sets: usize,
+}
+
+impl DisjointSet {
+    // n singletons: every element is its own root
+    pub fn new(n: usize) -> DisjointSet {
+        DisjointSet { parent: (0..n).collect(), rank: vec![0; n], sets: n }
+    }
+
+    pub fn find(&mut self, x: usize) -> usize {
+        // First pass: walk up to the root
+        let mut root = x;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+        // Second pass: repoint everything on the path at the root.
+        // Iterative rather than recursive, so long chains can't
+        // overflow the stack.
+        let mut node = x;
+        while self.parent[node] != root {
+            let next = self.parent[node];
+            self.parent[node] = root;
+            node = next;
+        }
+        root
+    }
+
+    // Merges the sets containing a and b.
+    // Returns false if they were already in the same set.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (ra, rb) = (self.find(a), self.find(b));
+        if ra == rb {
+            return false;
+        }
+        match self.rank[ra].cmp(&self.rank[rb]) {
+            Ordering::Less => self.parent[ra] = rb,
+            Ordering::Greater => self.parent[rb] = ra,
+            Ordering::Equal => {
+                self.parent[rb] = ra;
+                self.rank[ra] += 1;
+            }
+        }
+        self.sets -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    // Number of distinct sets
+    pub fn count(&self) -> usize {
+        self.sets
+    }
+
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn starts_as_singletons() {
+        let mut ds = DisjointSet::new(4);
+        assert_eq!(ds.count(), 4);
+        for i in 0..4 {
+            assert_eq!(ds.find(i), i);
+        }
+        assert!(!ds.connected(0, 1));
+    }
+
+    #[test]
+    fn union_reduces_count() {
+        let mut ds = DisjointSet::new(5);
+        assert!(ds.union(0, 1));
+        assert_eq!(ds.count(), 4);
+        assert!(ds.union(2, 3));
+        assert_eq!(ds.count(), 3);
+    }
+
+    #[test]
+    fn connected_is_transitive() {
+        let mut ds = DisjointSet::new(6);
+        ds.union(0, 1);
+        ds.union(1, 2);
+        ds.union(4, 5);
+        assert!(ds.connected(0, 2));
+        assert!(ds.connected(2, 0));
+        assert!(!ds.connected(0, 4));
+        ds.union(2, 5);
+        assert!(ds.connected(0, 4));
+    }
+
+    #[test]
+    fn repeated_union_is_idempotent() {
+        let mut ds = DisjointSet::new(3);
+        assert!(ds.union(0, 1));
+        assert!(!ds.union(0, 1));
+        assert!(!ds.union(1, 0));
+        assert_eq!(ds.count(), 2);
+    }
+
+    #[test]
+    fn long_chain_compresses() {
+        let n = 100_000;
+        let mut ds = DisjointSet::new(n);
+        for i in 1..n {
+            ds.union(i - 1, i);
+        }
+        assert_eq!(ds.count(), 1);
+        assert!(ds.connected(0, n - 1));
+    }
+}
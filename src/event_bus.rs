@@ -0,0 +1,101 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+
+// Type-driven publish/subscribe.
+//
+// Handlers are stored by the TypeId of the event they want. Publishing
+// an event looks up its TypeId and calls only those handlers.
+//
+// Why `'static`? TypeId and Any only work for types without borrowed
+// data: `Foo<'a>` and `Foo<'b>` would need distinct ids, but lifetimes
+// are erased at runtime. So every event type must be `'static` (it
+// owns its data), and handlers must be `'static` because the bus keeps
+// them around indefinitely.
+type Handler = Box<dyn Fn(&dyn Any)>;
+
+#[derive(Default)]
+pub struct EventBus {
+    handlers: HashMap<TypeId, Vec<Handler>>,
+}
+
+impl EventBus {
+    pub fn new() -> EventBus {
+        EventBus { handlers: HashMap::new() }
+    }
+
+    // Registers a handler for events of type E
+    pub fn subscribe<E: 'static>(&mut self, handler: impl Fn(&E) + 'static) {
+        // Wrap the typed handler in one that accepts &dyn Any and downcasts.
+        // The downcast always succeeds: we only call it for E's TypeId.
+        let wrapped = move |event: &dyn Any| {
+            if let Some(e) = event.downcast_ref::<E>() {
+                handler(e);
+            }
+        };
+        self.handlers
+            .entry(TypeId::of::<E>())
+            .or_default()
+            .push(Box::new(wrapped));
+    }
+
+    // Calls every handler subscribed to E, in subscription order
+    pub fn publish<E: 'static>(&self, event: &E) {
+        if let Some(handlers) = self.handlers.get(&TypeId::of::<E>()) {
+            for handler in handlers {
+                handler(event);
+            }
+        }
+    }
+
+    // Number of handlers listening for E
+    pub fn subscriber_count<E: 'static>(&self) -> usize {
+        self.handlers.get(&TypeId::of::<E>()).map_or(0, Vec::len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    struct Clicked(u32);
+    struct Closed;
+
+    #[test]
+    fn publish_only_triggers_matching_handlers() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut bus = EventBus::new();
+
+        let l = Rc::clone(&log);
+        bus.subscribe(move |e: &Clicked| l.borrow_mut().push(format!("click {}", e.0)));
+        let l = Rc::clone(&log);
+        bus.subscribe(move |_: &Closed| l.borrow_mut().push(String::from("closed")));
+
+        bus.publish(&Clicked(7));
+        assert_eq!(*log.borrow(), vec!["click 7"]);
+
+        bus.publish(&Closed);
+        assert_eq!(*log.borrow(), vec!["click 7", "closed"]);
+    }
+
+    #[test]
+    fn multiple_handlers_for_one_type() {
+        let count = Rc::new(RefCell::new(0));
+        let mut bus = EventBus::new();
+        for _ in 0..3 {
+            let c = Rc::clone(&count);
+            bus.subscribe(move |_: &Closed| *c.borrow_mut() += 1);
+        }
+        assert_eq!(bus.subscriber_count::<Closed>(), 3);
+        bus.publish(&Closed);
+        assert_eq!(*count.borrow(), 3);
+    }
+
+    #[test]
+    fn publish_with_no_subscribers_is_noop() {
+        let bus = EventBus::new();
+        bus.publish(&Clicked(1));
+        assert_eq!(bus.subscriber_count::<Clicked>(), 0);
+    }
+}
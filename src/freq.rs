@@ -0,0 +1,42 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// Counts each distinct item, most frequent first.
+// Ties are broken by the item's natural order so output is deterministic.
+pub fn frequencies<T: Eq + Hash + Clone + Ord>(items: &[T]) -> Vec<(T, usize)> {
+    let mut counts: HashMap<&T, usize> = HashMap::new();
+    for item in items {
+        *counts.entry(item).or_insert(0) += 1;
+    }
+
+    let mut ranked: Vec<(T, usize)> = counts
+        .into_iter()
+        .map(|(item, count)| (item.clone(), count))
+        .collect();
+    // Descending count, then ascending item
+    ranked.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_and_orders() {
+        let items = ["b", "a", "c", "a", "b", "a"];
+        assert_eq!(frequencies(&items), vec![("a", 3), ("b", 2), ("c", 1)]);
+    }
+
+    #[test]
+    fn empty_input() {
+        let items: [i32; 0] = [];
+        assert!(frequencies(&items).is_empty());
+    }
+
+    #[test]
+    fn ties_use_item_order() {
+        let items = [3, 1, 2, 3, 1, 2];
+        assert_eq!(frequencies(&items), vec![(1, 2), (2, 2), (3, 2)]);
+    }
+}
@@ -0,0 +1,91 @@
+// Reshaping a grid stored as Vec<Vec<T>> (one inner Vec per row).
+//
+// Both functions build a brand-new grid, cloning each element once, and
+// expect a rectangular input — every row the same length. A ragged grid
+// has no sensible transpose, so they panic rather than guess.
+
+// Rows become columns: out[c][r] = m[r][c]
+//
+//   1 2 3        1 4
+//   4 5 6   →    2 5
+//                3 6
+pub fn transpose<T: Clone>(m: &[Vec<T>]) -> Vec<Vec<T>> {
+    let cols = width(m);
+    (0..cols)
+        .map(|c| m.iter().map(|row| row[c].clone()).collect())
+        .collect()
+}
+
+// Quarter turn clockwise: the bottom-left corner ends up top-left.
+// Same as transposing and then reversing each row.
+//
+//   1 2 3        4 1
+//   4 5 6   →    5 2
+//                6 3
+pub fn rotate_90<T: Clone>(m: &[Vec<T>]) -> Vec<Vec<T>> {
+    let cols = width(m);
+    (0..cols)
+        .map(|c| m.iter().rev().map(|row| row[c].clone()).collect())
+        .collect()
+}
+
+// Row length shared by every row; 0 for an empty grid
+fn width<T>(m: &[Vec<T>]) -> usize {
+    let cols = m.first().map_or(0, Vec::len);
+    assert!(
+        m.iter().all(|row| row.len() == cols),
+        "grid is ragged: every row must have {} elements",
+        cols
+    );
+    cols
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transpose_2x3_gives_3x2() {
+        let m = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        assert_eq!(transpose(&m), vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+    }
+
+    #[test]
+    fn rotate_2x2_moves_corners_clockwise() {
+        let m = vec![vec!['a', 'b'], vec!['c', 'd']];
+        let r = rotate_90(&m);
+        // a (top-left) → top-right, c (bottom-left) → top-left
+        assert_eq!(r[0][1], 'a');
+        assert_eq!(r[0][0], 'c');
+        assert_eq!(r[1][1], 'b');
+        assert_eq!(r[1][0], 'd');
+    }
+
+    #[test]
+    fn four_rotations_is_identity() {
+        let m = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        let back = rotate_90(&rotate_90(&rotate_90(&rotate_90(&m))));
+        assert_eq!(back, m);
+    }
+
+    #[test]
+    fn empty_matrix_stays_empty() {
+        let m: Vec<Vec<i32>> = Vec::new();
+        assert!(transpose(&m).is_empty());
+        assert!(rotate_90(&m).is_empty());
+    }
+
+    #[test]
+    fn single_row_becomes_column() {
+        let m = vec![vec![1, 2, 3]];
+        assert_eq!(transpose(&m), vec![vec![1], vec![2], vec![3]]);
+        assert_eq!(rotate_90(&m), vec![vec![1], vec![2], vec![3]]);
+    }
+
+    #[test]
+    #[should_panic(expected = "ragged")]
+    fn ragged_input_panics() {
+        let m = vec![vec![1, 2], vec![3]];
+        transpose(&m);
+    }
+}
@@ -0,0 +1,38 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// Partitions items into buckets keyed by `key(&item)`.
+// Items keep their original relative order inside each bucket.
+pub fn group_by<T, K: Eq + Hash, F: Fn(&T) -> K>(items: Vec<T>, key: F) -> HashMap<K, Vec<T>> {
+    let mut groups: HashMap<K, Vec<T>> = HashMap::new();
+    for item in items {
+        groups.entry(key(&item)).or_default().push(item);
+    }
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups_into_correct_buckets() {
+        let groups = group_by(vec![1, 2, 3, 4, 5], |n| n % 2 == 0);
+        assert_eq!(groups[&true], vec![2, 4]);
+        assert_eq!(groups[&false], vec![1, 3, 5]);
+        assert_eq!(groups.len(), 2);
+    }
+
+    #[test]
+    fn empty_input_gives_empty_map() {
+        let groups = group_by(Vec::<i32>::new(), |n| *n);
+        assert!(groups.is_empty());
+    }
+
+    #[test]
+    fn shared_key_goes_to_one_bucket() {
+        let groups = group_by(vec!["apple", "avocado", "apricot"], |w| w.chars().next());
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[&Some('a')], vec!["apple", "avocado", "apricot"]);
+    }
+}
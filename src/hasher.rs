@@ -0,0 +1,161 @@
+// A hash table with a pluggable hash function (the strategy pattern).
+//
+// HashTable doesn't know or care how hashes are computed — it only needs
+// *something* implementing HashStrategy. Swapping FNV-1a for djb2 is a
+// change of type parameter, not of table code.
+//
+// Collisions are handled by chaining: each bucket is a Vec of (key, value)
+// pairs, and keys that land in the same bucket just share it. The bucket
+// count is fixed at construction to keep things simple; a real table
+// would grow once the chains got long.
+
+pub trait HashStrategy {
+    fn hash(&self, data: &[u8]) -> u64;
+}
+
+// FNV-1a: xor in each byte, then multiply by a large prime.
+// Fast, and spreads short keys well.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Fnv1a;
+
+impl HashStrategy for Fnv1a {
+    fn hash(&self, data: &[u8]) -> u64 {
+        const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+        const PRIME: u64 = 0x0100_0000_01b3;
+        data.iter()
+            .fold(OFFSET_BASIS, |h, &b| (h ^ b as u64).wrapping_mul(PRIME))
+    }
+}
+
+// djb2: h = h * 33 + byte, starting from 5381. Classic and tiny.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Djb2;
+
+impl HashStrategy for Djb2 {
+    fn hash(&self, data: &[u8]) -> u64 {
+        data.iter()
+            .fold(5381u64, |h, &b| h.wrapping_mul(33).wrapping_add(b as u64))
+    }
+}
+
+#[derive(Debug)]
+pub struct HashTable<V, S: HashStrategy> {
+    strategy: S,
+    buckets: Vec<Vec<(String, V)>>,
+    len: usize,
+}
+
+impl<V, S: HashStrategy> HashTable<V, S> {
+    pub fn new(strategy: S) -> Self {
+        HashTable::with_buckets(strategy, 16)
+    }
+
+    pub fn with_buckets(strategy: S, count: usize) -> Self {
+        assert!(count > 0, "a hash table needs at least one bucket");
+        let buckets = (0..count).map(|_| Vec::new()).collect();
+        HashTable {
+            strategy,
+            buckets,
+            len: 0,
+        }
+    }
+
+    fn bucket_index(&self, key: &str) -> usize {
+        (self.strategy.hash(key.as_bytes()) % self.buckets.len() as u64) as usize
+    }
+
+    // Returns the previous value if the key was already present
+    pub fn insert(&mut self, key: &str, value: V) -> Option<V> {
+        let i = self.bucket_index(key);
+        let bucket = &mut self.buckets[i];
+        if let Some((_, existing)) = bucket.iter_mut().find(|(k, _)| k == key) {
+            return Some(std::mem::replace(existing, value));
+        }
+        bucket.push((key.to_string(), value));
+        self.len += 1;
+        None
+    }
+
+    pub fn get(&self, key: &str) -> Option<&V> {
+        let bucket = &self.buckets[self.bucket_index(key)];
+        bucket.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Entries in the fullest bucket — lookups there are slowest, so a
+    // good strategy keeps this small
+    pub fn longest_chain(&self) -> usize {
+        self.buckets.iter().map(Vec::len).max().unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Sends everything to the same bucket, to force collisions
+    struct Constant;
+
+    impl HashStrategy for Constant {
+        fn hash(&self, _data: &[u8]) -> u64 {
+            7
+        }
+    }
+
+    fn round_trip<S: HashStrategy>(strategy: S) {
+        let mut table = HashTable::new(strategy);
+        for (i, word) in ["alpha", "beta", "gamma", "delta", "epsilon"]
+            .iter()
+            .enumerate()
+        {
+            assert_eq!(table.insert(word, i), None);
+        }
+        assert_eq!(table.len(), 5);
+        assert_eq!(table.get("gamma"), Some(&2));
+        assert_eq!(table.get("omega"), None);
+        assert_eq!(table.insert("gamma", 20), Some(2));
+        assert_eq!(table.get("gamma"), Some(&20));
+        assert_eq!(table.len(), 5);
+    }
+
+    #[test]
+    fn fnv1a_round_trip() {
+        round_trip(Fnv1a);
+    }
+
+    #[test]
+    fn djb2_round_trip() {
+        round_trip(Djb2);
+    }
+
+    #[test]
+    fn collisions_chain_in_one_bucket() {
+        let mut table = HashTable::new(Constant);
+        table.insert("a", 1);
+        table.insert("b", 2);
+        table.insert("c", 3);
+        assert_eq!(table.longest_chain(), 3);
+        assert_eq!(
+            (table.get("a"), table.get("b"), table.get("c")),
+            (Some(&1), Some(&2), Some(&3))
+        );
+    }
+
+    #[test]
+    fn hashes_are_stable() {
+        // Same input, same hash — every time, and matching published values
+        assert_eq!(Fnv1a.hash(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(Fnv1a.hash(b"a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(Djb2.hash(b""), 5381);
+        assert_eq!(Djb2.hash(b"a"), 5381 * 33 + 97);
+        assert_eq!(Fnv1a.hash(b"rust"), Fnv1a.hash(b"rust"));
+        assert_ne!(Djb2.hash(b"rust"), Djb2.hash(b"tsur"));
+    }
+}
@@ -0,0 +1,231 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap};
+
+// Huffman coding: frequent symbols get short bit strings, rare ones get
+// long ones, and no code is a prefix of another — so a stream of bits
+// decodes unambiguously without separators.
+//
+// Building the tree:
+// 1. Every symbol starts as a leaf weighted by its frequency
+// 2. Repeatedly merge the two lightest trees into one whose weight is
+//    their sum
+// 3. The last tree standing is the code tree: going left appends '0',
+//    going right appends '1', and each leaf's path is its code
+//
+// Codes are strings of '0'/'1' to keep things readable; a real
+// compressor would pack them into bytes.
+
+enum Node {
+    Leaf(char),
+    Internal(Box<Node>, Box<Node>),
+}
+
+// A subtree waiting in the heap. `seq` records creation order so equal
+// weights merge in a fixed order and the same table always gives the
+// same codes. Weights are u64: merged sums of u32 frequencies can pass
+// u32::MAX, but there are too few chars for their sum to overflow a u64.
+struct Pending {
+    weight: u64,
+    seq: usize,
+    node: Node,
+}
+
+// Node can't be compared, so order by (weight, seq) only
+impl Ord for Pending {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.weight, self.seq).cmp(&(other.weight, other.seq))
+    }
+}
+
+impl PartialOrd for Pending {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Pending {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Pending {}
+
+pub fn build_codes(freqs: &HashMap<char, u32>) -> HashMap<char, String> {
+    // Sorted so heap insertion order (and therefore seq) is deterministic
+    let mut symbols: Vec<(char, u32)> = freqs.iter().map(|(&c, &w)| (c, w)).collect();
+    symbols.sort_unstable();
+
+    // Reverse turns the max-heap into a min-heap: lightest first
+    let mut heap: BinaryHeap<Reverse<Pending>> = symbols
+        .into_iter()
+        .enumerate()
+        .map(|(seq, (c, weight))| {
+            Reverse(Pending {
+                weight: u64::from(weight),
+                seq,
+                node: Node::Leaf(c),
+            })
+        })
+        .collect();
+    let mut seq = heap.len();
+
+    while heap.len() > 1 {
+        let Reverse(a) = heap.pop().unwrap();
+        let Reverse(b) = heap.pop().unwrap();
+        heap.push(Reverse(Pending {
+            weight: a.weight + b.weight,
+            seq,
+            node: Node::Internal(Box::new(a.node), Box::new(b.node)),
+        }));
+        seq += 1;
+    }
+
+    let mut codes = HashMap::new();
+    match heap.pop() {
+        None => {}
+        // A lone symbol has no left/right to walk, so give it "0"
+        Some(Reverse(Pending {
+            node: Node::Leaf(c),
+            ..
+        })) => {
+            codes.insert(c, String::from("0"));
+        }
+        Some(Reverse(root)) => assign(&root.node, String::new(), &mut codes),
+    }
+    codes
+}
+
+fn assign(node: &Node, prefix: String, codes: &mut HashMap<char, String>) {
+    match node {
+        Node::Leaf(c) => {
+            codes.insert(*c, prefix);
+        }
+        Node::Internal(left, right) => {
+            assign(left, format!("{}0", prefix), codes);
+            assign(right, format!("{}1", prefix), codes);
+        }
+    }
+}
+
+// Concatenates the code of every char. None if a char has no code.
+pub fn encode(text: &str, codes: &HashMap<char, String>) -> Option<String> {
+    text.chars()
+        .map(|c| codes.get(&c).map(String::as_str))
+        .collect()
+}
+
+// Reads bits until they spell a complete code, emits that symbol, and
+// starts over. The prefix property guarantees the first match is right.
+// None on a char other than '0'/'1' or if the bits stop mid-code.
+pub fn decode(bits: &str, codes: &HashMap<char, String>) -> Option<String> {
+    let lookup: HashMap<&str, char> = codes.iter().map(|(&c, code)| (code.as_str(), c)).collect();
+
+    let mut text = String::new();
+    let mut start = 0;
+    for (i, bit) in bits.char_indices() {
+        if bit != '0' && bit != '1' {
+            return None;
+        }
+        if let Some(&c) = lookup.get(&bits[start..=i]) {
+            text.push(c);
+            start = i + 1;
+        }
+    }
+
+    if start == bits.len() {
+        Some(text)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(pairs: &[(char, u32)]) -> HashMap<char, u32> {
+        pairs.iter().copied().collect()
+    }
+
+    #[test]
+    fn known_table_has_prefix_property() {
+        let freqs = table(&[
+            ('a', 45),
+            ('b', 13),
+            ('c', 12),
+            ('d', 16),
+            ('e', 9),
+            ('f', 5),
+        ]);
+        let codes = build_codes(&freqs);
+        assert_eq!(codes.len(), 6);
+
+        // The most frequent symbol gets the shortest code
+        assert_eq!(codes[&'a'].len(), 1);
+        assert!(codes.values().all(|code| code.len() >= codes[&'a'].len()));
+        // Textbook optimum for this table: 224 bits
+        let cost: u32 = freqs.iter().map(|(c, w)| w * codes[c].len() as u32).sum();
+        assert_eq!(cost, 224);
+
+        for (x, code_x) in &codes {
+            for (y, code_y) in &codes {
+                if x != y {
+                    assert!(
+                        !code_y.starts_with(code_x.as_str()),
+                        "{} is a prefix of {}",
+                        code_x,
+                        code_y
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn single_symbol_gets_one_bit() {
+        let codes = build_codes(&table(&[('z', 7)]));
+        assert_eq!(codes[&'z'], "0");
+        let bits = encode("zzz", &codes).unwrap();
+        assert_eq!(bits, "000");
+        assert_eq!(decode(&bits, &codes), Some(String::from("zzz")));
+    }
+
+    #[test]
+    fn round_trip() {
+        let text = "abracadabra alakazam";
+        let mut freqs = HashMap::new();
+        for c in text.chars() {
+            *freqs.entry(c).or_insert(0) += 1;
+        }
+        let codes = build_codes(&freqs);
+        let bits = encode(text, &codes).unwrap();
+        assert!(bits.len() < text.len() * 8);
+        assert_eq!(decode(&bits, &codes), Some(String::from(text)));
+    }
+
+    #[test]
+    fn bad_input() {
+        let codes = build_codes(&table(&[('a', 3), ('b', 1), ('c', 1)]));
+        assert_eq!(encode("abx", &codes), None);
+        assert_eq!(decode("01x", &codes), None);
+        // 'b' has a 2-bit code, so stopping after its first bit is incomplete
+        assert_eq!(codes[&'b'].len(), 2);
+        let truncated = format!("{}{}", codes[&'a'], &codes[&'b'][..1]);
+        assert_eq!(decode(&truncated, &codes), None);
+    }
+
+    #[test]
+    fn huge_frequencies_do_not_overflow() {
+        // Any two of these sum past u32::MAX. Four equal weights make a
+        // balanced tree; a wrapped sum would look light and skew it.
+        let m = u32::MAX;
+        let codes = build_codes(&table(&[('a', m), ('b', m), ('c', m), ('d', m)]));
+        assert!(codes.values().all(|code| code.len() == 2), "{:?}", codes);
+    }
+
+    #[test]
+    fn empty_table() {
+        assert!(build_codes(&HashMap::new()).is_empty());
+    }
+}
@@ -0,0 +1,70 @@
+// Collapses overlapping ranges into the fewest equivalent ranges.
+//
+//   (1, 3) (2, 6) (8, 10) (10, 12)  →  (1, 6) (8, 12)
+//
+// Intervals are closed [start, end], so ranges that merely touch —
+// one ends exactly where the next starts — are merged too.
+//
+// After sorting by start, any interval that can merge with the current
+// one must come right after it, so one pass finishes the job.
+pub fn merge_intervals(mut intervals: Vec<(i64, i64)>) -> Vec<(i64, i64)> {
+    intervals.sort_unstable();
+
+    let mut merged: Vec<(i64, i64)> = Vec::with_capacity(intervals.len());
+    for (start, end) in intervals {
+        match merged.last_mut() {
+            // Overlaps or touches: stretch the current interval.
+            // max() matters when the new one sits entirely inside it.
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disjoint_unchanged() {
+        assert_eq!(
+            merge_intervals(vec![(5, 6), (1, 2), (8, 9)]),
+            vec![(1, 2), (5, 6), (8, 9)]
+        );
+    }
+
+    #[test]
+    fn overlapping_merged() {
+        assert_eq!(merge_intervals(vec![(1, 3), (2, 6), (5, 7)]), vec![(1, 7)]);
+    }
+
+    #[test]
+    fn contained_absorbed() {
+        assert_eq!(
+            merge_intervals(vec![(1, 10), (2, 3), (4, 8)]),
+            vec![(1, 10)]
+        );
+    }
+
+    #[test]
+    fn touching_merged() {
+        assert_eq!(
+            merge_intervals(vec![(1, 3), (3, 5), (6, 7)]),
+            vec![(1, 5), (6, 7)]
+        );
+    }
+
+    #[test]
+    fn negative_and_single_points() {
+        assert_eq!(
+            merge_intervals(vec![(-5, -1), (0, 0), (-1, -1)]),
+            vec![(-5, -1), (0, 0)]
+        );
+    }
+
+    #[test]
+    fn empty_input() {
+        assert!(merge_intervals(Vec::new()).is_empty());
+    }
+}
@@ -0,0 +1,76 @@
+// Edit distance: the fewest single-character insertions, deletions or
+// substitutions that turn `a` into `b`. "kitten" → "sitting" is 3.
+//
+// The textbook DP fills a table where cell (i, j) is the distance
+// between the first i chars of a and the first j chars of b. Each row
+// only reads the row above it, so two rows — `prev` and `curr` — are
+// enough: O(len(b)) memory instead of O(len(a) × len(b)).
+//
+// Works on chars, not bytes: "é" vs "e" is one substitution, not a
+// two-byte mismatch.
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+
+    // Row 0: turning "" into b[..j] takes j insertions
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    let mut curr = vec![0; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        // Column 0: turning a[..=i] into "" takes i + 1 deletions
+        curr[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != cb);
+            let deletion = prev[j + 1] + 1;
+            let insertion = curr[j] + 1;
+            curr[j + 1] = substitution.min(deletion).min(insertion);
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    // After the last swap, the final row is in `prev`
+    prev[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_is_zero() {
+        assert_eq!(levenshtein("rust", "rust"), 0);
+    }
+
+    #[test]
+    fn one_insertion() {
+        assert_eq!(levenshtein("cat", "cart"), 1);
+    }
+
+    #[test]
+    fn one_deletion() {
+        assert_eq!(levenshtein("cart", "cat"), 1);
+    }
+
+    #[test]
+    fn one_substitution() {
+        assert_eq!(levenshtein("cat", "cut"), 1);
+    }
+
+    #[test]
+    fn kitten_sitting() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("sitting", "kitten"), 3);
+    }
+
+    #[test]
+    fn empty_strings() {
+        assert_eq!(levenshtein("", ""), 0);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("abc", ""), 3);
+    }
+
+    #[test]
+    fn counts_chars_not_bytes() {
+        assert_eq!(levenshtein("café", "cafe"), 1);
+        assert_eq!(levenshtein("日本", "日本語"), 1);
+    }
+}
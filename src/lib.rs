@@ -0,0 +1,56 @@
+// Library half of the crate — reusable modules the examples can import.
+// Use from an example with: use rust_journey::safe_math;
+
+pub mod safe_math;
+pub mod strrev;
+pub mod group;
+pub mod sparse;
+pub mod template;
+pub mod freq;
+pub mod search;
+pub mod ringbuf;
+pub mod bimap;
+pub mod approx;
+pub mod event_bus;
+pub mod accumulate;
+pub mod shapes;
+pub mod dedup_iter;
+pub mod base64;
+pub mod kmerge;
+pub mod wrap;
+pub mod compose;
+pub mod tokenizer;
+pub mod memo_fib;
+pub mod backoff;
+pub mod lru;
+pub mod segtree;
+pub mod trie;
+pub mod dsu;
+pub mod weighted;
+pub mod toposort;
+pub mod window_max;
+pub mod checked_slice;
+pub mod dijkstra;
+pub mod grid_ops;
+pub mod ast;
+pub mod char_slice;
+pub mod paginate;
+pub mod huffman;
+pub mod linalg;
+pub mod running_median;
+pub mod intervals;
+pub mod range_builder;
+pub mod pretty;
+pub mod truncate;
+pub mod observers;
+pub mod subsum;
+pub mod rate;
+pub mod topk;
+pub mod bitset;
+pub mod argmin;
+pub mod ttl_cache;
+pub mod levenshtein;
+pub mod rolling_sum;
+pub mod hasher;
+pub mod argparse;
+pub mod transitions;
@@ -0,0 +1,119 @@
+// Determinant and inverse for 3×3 matrices, stored as fixed-size arrays
+// (m[row][col]). Fixed sizes mean no Vec allocation and no way to pass
+// a non-square matrix.
+
+// Relative tolerance for treating a determinant as zero. Exact == 0.0 is
+// too strict: rounding leaves singular matrices with tiny non-zero dets.
+// It's scaled by the matrix's size (see is_singular), since a fixed
+// cutoff would call 1e-5·I singular just because its det is 1e-15.
+const SINGULAR_EPSILON: f64 = 1e-12;
+
+// Cofactor expansion along the first row:
+//
+//   | a b c |
+//   | d e f |  =  a(ei − fh) − b(di − fg) + c(dh − eg)
+//   | g h i |
+pub fn determinant(m: &[[f64; 3]; 3]) -> f64 {
+    let [[a, b, c], [d, e, f], [g, h, i]] = *m;
+    a * (e * i - f * h) - b * (d * i - f * g) + c * (d * h - e * g)
+}
+
+// inverse = adjugate / determinant, where the adjugate is the transposed
+// matrix of cofactors. None when the determinant is (nearly) zero —
+// a singular matrix has no inverse.
+pub fn inverse(m: &[[f64; 3]; 3]) -> Option<[[f64; 3]; 3]> {
+    let det = determinant(m);
+    if is_singular(m, det) {
+        return None;
+    }
+
+    let mut inv = [[0.0; 3]; 3];
+    for (row, inv_row) in inv.iter_mut().enumerate() {
+        for (col, cell) in inv_row.iter_mut().enumerate() {
+            // Transposed: entry (row, col) comes from the cofactor at (col, row).
+            // Taking rows/cols cyclically (+1, +2) bakes in the ± sign pattern.
+            let (r1, r2) = ((col + 1) % 3, (col + 2) % 3);
+            let (c1, c2) = ((row + 1) % 3, (row + 2) % 3);
+            let cofactor = m[r1][c1] * m[r2][c2] - m[r1][c2] * m[r2][c1];
+            *cell = cofactor / det;
+        }
+    }
+    Some(inv)
+}
+
+// The determinant is a sum of products of three entries, so it scales
+// with the cube of the largest entry. Compare against that, not a fixed
+// threshold. A non-finite det (from inf/NaN entries) also counts.
+fn is_singular(m: &[[f64; 3]; 3], det: f64) -> bool {
+    let largest = m.iter().flatten().fold(0.0_f64, |acc, x| acc.max(x.abs()));
+    !det.is_finite() || det.abs() <= SINGULAR_EPSILON * largest.powi(3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_approx;
+
+    const IDENTITY: [[f64; 3]; 3] = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+    fn multiply(a: &[[f64; 3]; 3], b: &[[f64; 3]; 3]) -> [[f64; 3]; 3] {
+        let mut out = [[0.0; 3]; 3];
+        for i in 0..3 {
+            for j in 0..3 {
+                out[i][j] = (0..3).map(|k| a[i][k] * b[k][j]).sum();
+            }
+        }
+        out
+    }
+
+    #[test]
+    fn identity_determinant_is_one() {
+        assert_eq!(determinant(&IDENTITY), 1.0);
+    }
+
+    #[test]
+    fn known_determinant() {
+        let m = [[6.0, 1.0, 1.0], [4.0, -2.0, 5.0], [2.0, 8.0, 7.0]];
+        assert_approx!(determinant(&m), -306.0);
+    }
+
+    #[test]
+    fn inverse_times_original_is_identity() {
+        let m = [[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]];
+        let inv = inverse(&m).unwrap();
+        for product in [multiply(&m, &inv), multiply(&inv, &m)] {
+            for i in 0..3 {
+                for j in 0..3 {
+                    assert_approx!(product[i][j], IDENTITY[i][j]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn singular_matrix_has_no_inverse() {
+        // Third row = first + second, so the rows are linearly dependent
+        let m = [[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [5.0, 7.0, 9.0]];
+        assert_eq!(inverse(&m), None);
+        assert_eq!(inverse(&[[0.0; 3]; 3]), None);
+    }
+
+    #[test]
+    fn small_scale_matrix_is_invertible() {
+        // det is 1e-15, but the matrix is perfectly well-conditioned
+        let scale = 1e-5;
+        let m = IDENTITY.map(|row| row.map(|x| x * scale));
+        let inv = inverse(&m).unwrap();
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_approx!(inv[i][j], IDENTITY[i][j] / scale);
+            }
+        }
+    }
+
+    #[test]
+    fn scaled_singular_matrix_has_no_inverse() {
+        let m = [[1e6, 2e6, 3e6], [4e6, 5e6, 6e6], [5e6, 7e6, 9e6]];
+        assert_eq!(inverse(&m), None);
+    }
+}
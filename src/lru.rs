@@ -0,0 +1,119 @@
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
+
+// Least-recently-used cache with a fixed capacity.
+//
+// `map` holds the values; `order` holds the keys from least recently
+// used (front) to most recently used (back). Touching a key moves it to
+// the back; evicting pops from the front.
+//
+// Moving a key within the VecDeque is O(n). That's fine for small caches
+// and keeps the code simple — production LRUs use a linked list threaded
+// through the map for O(1) updates.
+#[derive(Debug)]
+pub struct LruCache<K: Eq + Hash + Clone, V> {
+    cap: usize,
+    map: HashMap<K, V>,
+    order: VecDeque<K>,
+}
+
+impl<K: Eq + Hash + Clone, V> LruCache<K, V> {
+    pub fn new(cap: usize) -> Self {
+        assert!(cap > 0, "LRU cache capacity must be at least 1");
+        LruCache { cap, map: HashMap::with_capacity(cap), order: VecDeque::with_capacity(cap) }
+    }
+
+    // Returns the value and marks the key as most recently used
+    pub fn get(&mut self, k: &K) -> Option<&V> {
+        if self.map.contains_key(k) {
+            self.touch(k);
+        }
+        self.map.get(k)
+    }
+
+    // Inserts or overwrites. Evicts the least recently used entry if a
+    // new key pushes the cache past capacity; returns what was evicted.
+    pub fn put(&mut self, k: K, v: V) -> Option<(K, V)> {
+        if self.map.insert(k.clone(), v).is_some() {
+            // Existing key: value replaced, just refresh its position
+            self.touch(&k);
+            return None;
+        }
+
+        self.order.push_back(k);
+        if self.map.len() > self.cap {
+            let oldest = self.order.pop_front()?;
+            let value = self.map.remove(&oldest)?;
+            return Some((oldest, value));
+        }
+        None
+    }
+
+    pub fn contains(&self, k: &K) -> bool {
+        self.map.contains_key(k)
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    // Move `k` to the most-recently-used end
+    fn touch(&mut self, k: &K) {
+        if let Some(pos) = self.order.iter().position(|key| key == k)
+            && let Some(key) = self.order.remove(pos)
+        {
+            self.order.push_back(key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn evicts_least_recently_used() {
+        let mut cache = LruCache::new(2);
+        cache.put("a", 1);
+        cache.put("b", 2);
+        let evicted = cache.put("c", 3);
+        assert_eq!(evicted, Some(("a", 1)));
+        assert!(!cache.contains(&"a"));
+        assert!(cache.contains(&"b"));
+        assert!(cache.contains(&"c"));
+    }
+
+    #[test]
+    fn get_refreshes_recency() {
+        let mut cache = LruCache::new(2);
+        cache.put("a", 1);
+        cache.put("b", 2);
+        assert_eq!(cache.get(&"a"), Some(&1)); // "b" is now the oldest
+        cache.put("c", 3);
+        assert!(cache.contains(&"a"));
+        assert!(!cache.contains(&"b"));
+    }
+
+    #[test]
+    fn overwrite_existing_key() {
+        let mut cache = LruCache::new(2);
+        cache.put("a", 1);
+        cache.put("b", 2);
+        assert_eq!(cache.put("a", 10), None); // no eviction, "a" refreshed
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get(&"a"), Some(&10));
+        cache.put("c", 3);
+        assert!(!cache.contains(&"b"));
+    }
+
+    #[test]
+    fn get_missing_key() {
+        let mut cache: LruCache<&str, i32> = LruCache::new(1);
+        assert_eq!(cache.get(&"nope"), None);
+        assert!(cache.is_empty());
+    }
+}
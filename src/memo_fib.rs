@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+// Memoized fibonacci — dynamic programming with a HashMap.
+//
+// The naive recursion recomputes the same subproblems over and over
+// (fib(40) makes ~330 million calls). Caching each answer the first
+// time it's computed makes it linear: every n is solved once.
+//
+// u64 holds up to fib(93); fib(94) overflows. Use fib_checked if n
+// might be that large.
+//
+// Capping n also caps the recursion depth: without the cap, a huge n
+// would recurse n levels deep and blow the stack before any addition
+// had a chance to overflow.
+const MAX_N: u64 = 93;
+
+// Panics if n > 93, since the answer doesn't fit in a u64
+pub fn fib_memo(n: u64, cache: &mut HashMap<u64, u64>) -> u64 {
+    assert!(n <= MAX_N, "fib({}) overflows u64; use fib_checked", n);
+    if n < 2 {
+        return n;
+    }
+    if let Some(&cached) = cache.get(&n) {
+        return cached;
+    }
+    let result = fib_memo(n - 1, cache) + fib_memo(n - 2, cache);
+    cache.insert(n, result);
+    result
+}
+
+// Convenience wrapper that creates a fresh cache
+pub fn fib(n: u64) -> u64 {
+    fib_memo(n, &mut HashMap::new())
+}
+
+// Exponential-time version, kept for comparison
+pub fn fib_naive(n: u64) -> u64 {
+    if n < 2 { n } else { fib_naive(n - 1) + fib_naive(n - 2) }
+}
+
+// None instead of overflowing
+pub fn fib_checked(n: u64) -> Option<u64> {
+    if n > MAX_N {
+        return None;
+    }
+    fib_checked_memo(n, &mut HashMap::new())
+}
+
+fn fib_checked_memo(n: u64, cache: &mut HashMap<u64, u64>) -> Option<u64> {
+    if n < 2 {
+        return Some(n);
+    }
+    if let Some(&cached) = cache.get(&n) {
+        return Some(cached);
+    }
+    let result = fib_checked_memo(n - 1, cache)?.checked_add(fib_checked_memo(n - 2, cache)?)?;
+    cache.insert(n, result);
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base_cases() {
+        assert_eq!(fib(0), 0);
+        assert_eq!(fib(1), 1);
+    }
+
+    #[test]
+    fn fib_ten() {
+        assert_eq!(fib(10), 55);
+    }
+
+    #[test]
+    fn memo_agrees_with_naive() {
+        for n in 0..20 {
+            assert_eq!(fib(n), fib_naive(n), "n = {}", n);
+        }
+    }
+
+    #[test]
+    fn cache_is_reused() {
+        let mut cache = HashMap::new();
+        fib_memo(30, &mut cache);
+        assert_eq!(cache.get(&30), Some(&832_040));
+        assert_eq!(cache.len(), 29); // 2..=30
+    }
+
+    #[test]
+    fn checked_detects_overflow() {
+        assert_eq!(fib_checked(93), Some(12_200_160_415_121_876_738));
+        assert_eq!(fib_checked(94), None);
+        assert_eq!(fib_checked(10), Some(55));
+    }
+
+    #[test]
+    fn checked_huge_n_returns_none() {
+        // Would overflow the stack if it recursed before checking
+        assert_eq!(fib_checked(1_000_000), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows u64")]
+    fn memo_panics_past_limit() {
+        fib(94);
+    }
+}
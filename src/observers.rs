@@ -0,0 +1,112 @@
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+// Observer pattern without keeping observers alive.
+//
+// If the subject held Rc<RefCell<dyn Observer>>, every subscribed
+// observer would live as long as the subject, even after the rest of the
+// program had dropped it. Holding Weak instead means the subject never
+// owns its observers: once the last Rc goes, upgrade() returns None and
+// the stale entry is pruned on the next notify.
+//
+// RefCell is needed because observers update themselves in on_notify
+// while being shared through Rc.
+pub trait Observer {
+    fn on_notify(&mut self, value: i32);
+}
+
+#[derive(Default)]
+pub struct Subject {
+    observers: Vec<Weak<RefCell<dyn Observer>>>,
+}
+
+impl Subject {
+    pub fn new() -> Self {
+        Subject::default()
+    }
+
+    // Takes &Rc so the caller keeps ownership; we only store a Weak.
+    // The second `let` coerces Weak<RefCell<O>> into
+    // Weak<RefCell<dyn Observer>>.
+    pub fn subscribe<O: Observer + 'static>(&mut self, observer: &Rc<RefCell<O>>) {
+        let weak: Weak<RefCell<O>> = Rc::downgrade(observer);
+        let weak: Weak<RefCell<dyn Observer>> = weak;
+        self.observers.push(weak);
+    }
+
+    // Delivers `value` to every live observer and forgets the dead ones
+    pub fn notify(&mut self, value: i32) {
+        self.observers.retain(|weak| match weak.upgrade() {
+            Some(observer) => {
+                observer.borrow_mut().on_notify(value);
+                true
+            }
+            None => false,
+        });
+    }
+
+    // Entries still registered, including any dropped since the last
+    // notify (they're only pruned when notify runs)
+    pub fn observer_count(&self) -> usize {
+        self.observers.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Recorder {
+        seen: Vec<i32>,
+    }
+
+    impl Observer for Recorder {
+        fn on_notify(&mut self, value: i32) {
+            self.seen.push(value);
+        }
+    }
+
+    fn recorder() -> Rc<RefCell<Recorder>> {
+        Rc::new(RefCell::new(Recorder { seen: Vec::new() }))
+    }
+
+    #[test]
+    fn live_observer_receives_values() {
+        let mut subject = Subject::new();
+        let r = recorder();
+        subject.subscribe(&r);
+
+        subject.notify(1);
+        subject.notify(2);
+        assert_eq!(r.borrow().seen, vec![1, 2]);
+    }
+
+    #[test]
+    fn dropped_observer_is_pruned() {
+        let mut subject = Subject::new();
+        let kept = recorder();
+        let dropped = recorder();
+        subject.subscribe(&kept);
+        subject.subscribe(&dropped);
+
+        subject.notify(1);
+        // Keep a Weak of our own to check the observer really was freed
+        let probe = Rc::downgrade(&dropped);
+        drop(dropped);
+        assert!(probe.upgrade().is_none());
+        assert_eq!(subject.observer_count(), 2);
+
+        subject.notify(2);
+        assert_eq!(subject.observer_count(), 1);
+        assert_eq!(kept.borrow().seen, vec![1, 2]);
+    }
+
+    #[test]
+    fn subject_does_not_keep_observers_alive() {
+        let mut subject = Subject::new();
+        let r = recorder();
+        subject.subscribe(&r);
+        assert_eq!(Rc::strong_count(&r), 1);
+        assert_eq!(Rc::weak_count(&r), 1);
+    }
+}
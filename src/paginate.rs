@@ -0,0 +1,67 @@
+// Splitting a list into numbered pages, the way a UI or an API would.
+//
+// Pages are 1-based: page 1 is the first `per_page` items. Anything that
+// doesn't name a real page — page 0, a page past the end, or a page size
+// of 0 — simply yields no items.
+
+pub fn paginate<T: Clone>(items: &[T], page: usize, per_page: usize) -> Vec<T> {
+    if page == 0 || per_page == 0 {
+        return Vec::new();
+    }
+    // skip/take instead of slicing, so a start past the end is just empty;
+    // saturating_mul keeps huge page numbers from overflowing
+    items
+        .iter()
+        .skip((page - 1).saturating_mul(per_page))
+        .take(per_page)
+        .cloned()
+        .collect()
+}
+
+// How many pages `total` items need — a partial last page still counts
+pub fn page_count(total: usize, per_page: usize) -> usize {
+    if per_page == 0 {
+        return 0;
+    }
+    total.div_ceil(per_page)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_page() {
+        let items: Vec<u32> = (1..=10).collect();
+        assert_eq!(paginate(&items, 1, 3), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn last_page_is_partial() {
+        let items: Vec<u32> = (1..=10).collect();
+        assert_eq!(paginate(&items, 4, 3), vec![10]);
+    }
+
+    #[test]
+    fn page_beyond_end_is_empty() {
+        let items: Vec<u32> = (1..=10).collect();
+        assert!(paginate(&items, 5, 3).is_empty());
+        assert!(paginate(&items, usize::MAX, usize::MAX).is_empty());
+        assert!(paginate(&items, 0, 3).is_empty());
+    }
+
+    #[test]
+    fn zero_per_page_is_empty() {
+        let items = ["a", "b"];
+        assert!(paginate(&items, 1, 0).is_empty());
+        assert_eq!(page_count(items.len(), 0), 0);
+    }
+
+    #[test]
+    fn page_count_rounds_up() {
+        assert_eq!(page_count(10, 3), 4);
+        assert_eq!(page_count(9, 3), 3);
+        assert_eq!(page_count(1, 50), 1);
+        assert_eq!(page_count(0, 3), 0);
+    }
+}
@@ -0,0 +1,85 @@
+// Indented rendering for tree-shaped data:
+//
+//   src
+//     main.rs
+//     bin
+//       tool.rs
+//
+// `indent` is the nesting level, not a number of spaces — each level
+// adds two spaces. Lines are joined with '\n' with no trailing newline,
+// so the result drops straight into println!.
+
+pub trait Pretty {
+    fn pretty(&self, indent: usize) -> String;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    pub name: String,
+    pub children: Vec<Node>,
+}
+
+impl Node {
+    pub fn leaf(name: &str) -> Self {
+        Node {
+            name: name.to_string(),
+            children: Vec::new(),
+        }
+    }
+
+    pub fn branch(name: &str, children: Vec<Node>) -> Self {
+        Node {
+            name: name.to_string(),
+            children,
+        }
+    }
+}
+
+impl Pretty for Node {
+    // Render this line, then ask each child to render itself one level
+    // deeper — the recursion does all the indentation bookkeeping
+    fn pretty(&self, indent: usize) -> String {
+        let mut out = format!("{}{}", "  ".repeat(indent), self.name);
+        for child in &self.children {
+            out.push('\n');
+            out.push_str(&child.pretty(indent + 1));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leaf_is_one_line() {
+        assert_eq!(Node::leaf("file.txt").pretty(0), "file.txt");
+        assert_eq!(Node::leaf("file.txt").pretty(2), "    file.txt");
+    }
+
+    #[test]
+    fn children_indented_one_level() {
+        let tree = Node::branch("root", vec![Node::leaf("a"), Node::leaf("b")]);
+        assert_eq!(tree.pretty(0), "root\n  a\n  b");
+    }
+
+    #[test]
+    fn deep_nesting() {
+        let tree = Node::branch(
+            "1",
+            vec![
+                Node::branch("2", vec![Node::branch("3", vec![Node::leaf("4")])]),
+                Node::leaf("2b"),
+            ],
+        );
+        assert_eq!(tree.pretty(0), "1\n  2\n    3\n      4\n  2b");
+    }
+
+    #[test]
+    fn empty_children_renders_like_leaf() {
+        let empty_dir = Node::branch("empty", Vec::new());
+        assert_eq!(empty_dir.pretty(1), "  empty");
+        assert_eq!(empty_dir.pretty(1), Node::leaf("empty").pretty(1));
+    }
+}
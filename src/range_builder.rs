@@ -0,0 +1,108 @@
+// Float ranges with a step — `(0.0..1.0).step_by(0.25)` doesn't exist,
+// because Range<f64> isn't an iterator.
+//
+// Values run from `start` (inclusive) toward `stop` (exclusive). Each one
+// is computed as start + i * step rather than by adding step repeatedly,
+// so rounding error doesn't pile up over long ranges.
+//
+// The number of values is worked out before generating any, and ranges
+// longer than MAX_LEN are rejected. That also catches a step too small
+// to move start at all (1e20 + 1e-10 == 1e20), which would otherwise
+// loop forever.
+const MAX_LEN: f64 = 10_000_000.0;
+
+pub fn float_range(start: f64, stop: f64, step: f64) -> Result<Vec<f64>, String> {
+    if !(start.is_finite() && stop.is_finite() && step.is_finite()) {
+        return Err(String::from("start, stop and step must be finite"));
+    }
+    if step == 0.0 {
+        return Err(String::from("step must not be zero"));
+    }
+    // A step away from stop would never arrive
+    if (stop - start) * step < 0.0 {
+        return Err(format!(
+            "step {} moves away from {} toward {}",
+            step, start, stop
+        ));
+    }
+
+    // May be inf if stop - start overflows
+    let len = ((stop - start) / step).ceil();
+    if len > MAX_LEN {
+        return Err(format!(
+            "step {} from {} to {} gives too many values (limit {})",
+            step, start, stop, MAX_LEN
+        ));
+    }
+
+    // len can be off by one after rounding, so go one further and let the
+    // exclusive-end check decide
+    let mut values = Vec::with_capacity(len as usize);
+    for i in 0..=len as u64 {
+        let x = start + i as f64 * step;
+        // Exclusive end, in whichever direction we're heading
+        let before_stop = if step > 0.0 { x < stop } else { x > stop };
+        if !before_stop {
+            break;
+        }
+        values.push(x);
+    }
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_approx;
+
+    #[test]
+    fn ascending() {
+        assert_eq!(float_range(0.0, 1.0, 0.25), Ok(vec![0.0, 0.25, 0.5, 0.75]));
+    }
+
+    #[test]
+    fn step_overshoots_stop() {
+        // 0.0, 0.4, 0.8 — the next value 1.2 passes stop
+        let values = float_range(0.0, 1.0, 0.4).unwrap();
+        assert_eq!(values.len(), 3);
+        assert_approx!(values[2], 0.8);
+    }
+
+    #[test]
+    fn no_drift_over_many_steps() {
+        // Adding 0.1 ten times gives 0.9999999999999999, which would sneak
+        // an 11th value in under stop
+        let values = float_range(0.0, 1.0, 0.1).unwrap();
+        assert_eq!(values.len(), 10);
+    }
+
+    #[test]
+    fn descending_with_negative_step() {
+        assert_eq!(float_range(1.0, -0.5, -0.5), Ok(vec![1.0, 0.5, 0.0]));
+    }
+
+    #[test]
+    fn start_equals_stop_is_empty() {
+        assert_eq!(float_range(2.0, 2.0, 1.0), Ok(vec![]));
+    }
+
+    #[test]
+    fn zero_step_is_an_error() {
+        assert!(float_range(0.0, 1.0, 0.0).unwrap_err().contains("zero"));
+    }
+
+    #[test]
+    fn wrong_direction_is_an_error() {
+        assert!(float_range(0.0, 1.0, -0.1).is_err());
+        assert!(float_range(1.0, 0.0, 0.1).is_err());
+        assert!(float_range(0.0, f64::NAN, 0.1).is_err());
+    }
+
+    #[test]
+    fn too_many_values_is_an_error() {
+        // The step is too small to change start at all
+        let err = float_range(1e20, 2e20, 1e-10).unwrap_err();
+        assert!(err.contains("too many"), "got: {}", err);
+        assert!(float_range(-f64::MAX, f64::MAX, 1.0).is_err());
+    }
+}
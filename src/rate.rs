@@ -0,0 +1,132 @@
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+// Events per second over a trailing window, e.g. "requests in the last 10s".
+//
+// Timestamps are kept oldest-first in a VecDeque: new events go on the
+// back, expired ones come off the front, both O(1). Callers pass `now`
+// explicitly instead of the counter calling Instant::now() itself, which
+// keeps it deterministic and easy to test.
+#[derive(Debug, Clone)]
+pub struct RateCounter {
+    window: Duration,
+    events: VecDeque<Instant>,
+}
+
+impl RateCounter {
+    // Panics on a zero window — a rate over no time is meaningless
+    pub fn new(window: Duration) -> Self {
+        assert!(!window.is_zero(), "rate window must be longer than zero");
+        RateCounter {
+            window,
+            events: VecDeque::new(),
+        }
+    }
+
+    // Timestamps should arrive in order; an earlier `now` than the last
+    // event is treated as happening at the same moment. Clamping keeps
+    // the deque sorted, which count() and prune() rely on to stop early.
+    pub fn record(&mut self, now: Instant) {
+        let now = self.events.back().map_or(now, |&last| now.max(last));
+        self.prune(now);
+        self.events.push_back(now);
+    }
+
+    // Events in (now - window, now]
+    pub fn count(&self, now: Instant) -> usize {
+        // Newest are at the back, so stop at the first one too old
+        self.events
+            .iter()
+            .rev()
+            .take_while(|&&t| self.in_window(t, now))
+            .count()
+    }
+
+    pub fn rate_per_sec(&self, now: Instant) -> f64 {
+        self.count(now) as f64 / self.window.as_secs_f64()
+    }
+
+    // Drops everything that has slid out of the window. record() calls
+    // this, so memory stays bounded by the events in one window.
+    pub fn prune(&mut self, now: Instant) {
+        while let Some(&oldest) = self.events.front() {
+            if self.in_window(oldest, now) {
+                break;
+            }
+            self.events.pop_front();
+        }
+    }
+
+    fn in_window(&self, t: Instant, now: Instant) -> bool {
+        now.saturating_duration_since(t) < self.window
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secs(s: f64) -> Duration {
+        Duration::from_secs_f64(s)
+    }
+
+    #[test]
+    fn counts_events_in_window() {
+        let start = Instant::now();
+        let mut rc = RateCounter::new(secs(10.0));
+        for t in [0.0, 1.0, 2.5, 4.0, 9.0] {
+            rc.record(start + secs(t));
+        }
+        let now = start + secs(9.5);
+        assert_eq!(rc.count(now), 5);
+        assert_eq!(rc.rate_per_sec(now), 0.5);
+    }
+
+    #[test]
+    fn old_events_pruned() {
+        let start = Instant::now();
+        let mut rc = RateCounter::new(secs(2.0));
+        rc.record(start);
+        rc.record(start + secs(1.0));
+        rc.record(start + secs(5.0));
+        // Only the last one is within 2s of t=5; the others were dropped
+        assert_eq!(rc.events.len(), 1);
+        assert_eq!(rc.count(start + secs(5.0)), 1);
+        // Exactly `window` old is out
+        assert_eq!(rc.count(start + secs(7.0)), 0);
+    }
+
+    #[test]
+    fn count_ignores_expired_without_pruning() {
+        let start = Instant::now();
+        let mut rc = RateCounter::new(secs(1.0));
+        rc.record(start);
+        rc.record(start + secs(0.5));
+        assert_eq!(rc.count(start + secs(1.2)), 1);
+        assert_eq!(rc.events.len(), 2);
+        rc.prune(start + secs(1.2));
+        assert_eq!(rc.events.len(), 1);
+    }
+
+    #[test]
+    fn out_of_order_event_clamped_to_last() {
+        let start = Instant::now();
+        let mut rc = RateCounter::new(secs(2.5));
+        rc.record(start + secs(5.0));
+        rc.record(start + secs(3.0)); // earlier — counts as t=5
+        assert_eq!(rc.count(start + secs(7.0)), 2);
+        assert_eq!(rc.count(start + secs(7.5)), 0);
+    }
+
+    #[test]
+    fn empty_window_is_zero() {
+        let rc = RateCounter::new(secs(5.0));
+        assert_eq!(rc.rate_per_sec(Instant::now()), 0.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "longer than zero")]
+    fn zero_window_panics() {
+        RateCounter::new(Duration::ZERO);
+    }
+}
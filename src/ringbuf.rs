@@ -0,0 +1,118 @@
+// Fixed-capacity FIFO that overwrites its oldest item when full.
+//
+// `head` is where the next pop reads, `tail` is where the next push
+// writes. Both wrap around with `% cap`, so the buffer never moves data.
+#[derive(Debug)]
+pub struct RingBuffer<T> {
+    buf: Vec<Option<T>>,
+    head: usize,
+    tail: usize,
+    len: usize,
+    cap: usize,
+}
+
+impl<T> RingBuffer<T> {
+    // Panics if cap is 0 — there'd be nowhere to put anything
+    pub fn new(cap: usize) -> RingBuffer<T> {
+        assert!(cap > 0, "ring buffer capacity must be at least 1");
+        let mut buf = Vec::with_capacity(cap);
+        buf.resize_with(cap, || None);
+        RingBuffer { buf, head: 0, tail: 0, len: 0, cap }
+    }
+
+    // When full, the oldest item is dropped to make room
+    pub fn push(&mut self, item: T) {
+        if self.is_full() {
+            // Oldest slot is about to be overwritten — move head past it
+            self.head = (self.head + 1) % self.cap;
+        } else {
+            self.len += 1;
+        }
+        self.buf[self.tail] = Some(item);
+        self.tail = (self.tail + 1) % self.cap;
+    }
+
+    // Removes and returns the oldest item
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let item = self.buf[self.head].take();
+        self.head = (self.head + 1) % self.cap;
+        self.len -= 1;
+        item
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len == self.cap
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.cap
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fills_to_capacity() {
+        let mut rb = RingBuffer::new(3);
+        rb.push(1);
+        rb.push(2);
+        assert!(!rb.is_full());
+        rb.push(3);
+        assert!(rb.is_full());
+        assert_eq!(rb.len(), 3);
+    }
+
+    #[test]
+    fn overwrite_drops_oldest() {
+        let mut rb = RingBuffer::new(3);
+        for i in 1..=5 {
+            rb.push(i);
+        }
+        assert_eq!(rb.len(), 3);
+        assert_eq!(rb.pop(), Some(3));
+        assert_eq!(rb.pop(), Some(4));
+        assert_eq!(rb.pop(), Some(5));
+        assert_eq!(rb.pop(), None);
+    }
+
+    #[test]
+    fn pop_is_fifo() {
+        let mut rb = RingBuffer::new(4);
+        rb.push('a');
+        rb.push('b');
+        rb.push('c');
+        assert_eq!(rb.pop(), Some('a'));
+        assert_eq!(rb.pop(), Some('b'));
+        assert_eq!(rb.pop(), Some('c'));
+        assert!(rb.is_empty());
+    }
+
+    #[test]
+    fn wraps_across_boundary() {
+        let mut rb = RingBuffer::new(3);
+        rb.push(1);
+        rb.push(2);
+        assert_eq!(rb.pop(), Some(1));
+        // tail is now at index 2; these pushes wrap to index 0 and 1
+        rb.push(3);
+        rb.push(4);
+        assert!(rb.is_full());
+        assert_eq!(rb.pop(), Some(2));
+        assert_eq!(rb.pop(), Some(3));
+        assert_eq!(rb.pop(), Some(4));
+        assert_eq!(rb.pop(), None);
+    }
+}
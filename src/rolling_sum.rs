@@ -0,0 +1,76 @@
+// Sum of every `window`-length run of consecutive elements:
+//
+//   [1, 2, 3, 4, 5], window 3  →  [6, 9, 12]
+//
+// The obvious `data.windows(window).map(|w| w.iter().sum())` re-adds
+// every element of every window: O(n × window). But consecutive windows
+// share all but two elements, so slide instead — add the element that
+// enters, subtract the one that leaves. O(n) regardless of window size.
+//
+// A window of 0, or longer than the data, has no positions: empty Vec.
+pub fn rolling_sums(data: &[i64], window: usize) -> Vec<i64> {
+    if window == 0 || window > data.len() {
+        return Vec::new();
+    }
+
+    let mut sum: i64 = data[..window].iter().sum();
+    let mut sums = Vec::with_capacity(data.len() - window + 1);
+    sums.push(sum);
+
+    // data[i] enters as data[i - window] leaves
+    for i in window..data.len() {
+        sum += data[i] - data[i - window];
+        sums.push(sum);
+    }
+    sums
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive(data: &[i64], window: usize) -> Vec<i64> {
+        if window == 0 {
+            return Vec::new();
+        }
+        data.windows(window).map(|w| w.iter().sum()).collect()
+    }
+
+    #[test]
+    fn matches_naive() {
+        let inputs: [&[i64]; 4] = [
+            &[1, 2, 3, 4, 5],
+            &[5, -3, 8, 0, -2, 7, 1],
+            &[10, 10, 10],
+            &[-1, -2, -3, -4],
+        ];
+        for data in inputs {
+            for window in 1..=data.len() {
+                assert_eq!(
+                    rolling_sums(data, window),
+                    naive(data, window),
+                    "{:?} window {}",
+                    data,
+                    window
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn window_of_one_is_the_data() {
+        assert_eq!(rolling_sums(&[4, -1, 7], 1), vec![4, -1, 7]);
+    }
+
+    #[test]
+    fn window_equal_to_len_is_total() {
+        assert_eq!(rolling_sums(&[4, -1, 7], 3), vec![10]);
+    }
+
+    #[test]
+    fn window_too_big_or_zero_is_empty() {
+        assert!(rolling_sums(&[1, 2], 3).is_empty());
+        assert!(rolling_sums(&[1, 2], 0).is_empty());
+        assert!(rolling_sums(&[], 1).is_empty());
+    }
+}
@@ -0,0 +1,150 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+// Median of a growing stream, O(log n) per value.
+//
+// The values are split into two halves:
+//   lower — a max-heap, so its top is the largest of the small values
+//   upper — a min-heap (Reverse), so its top is the smallest of the large
+//
+// Kept balanced so `lower` has the same number of items as `upper`, or
+// one more. Then the median is either lower's top (odd count) or the
+// average of both tops (even count) — no sorting needed.
+#[derive(Debug, Default)]
+pub struct RunningMedian {
+    lower: BinaryHeap<Total>,
+    upper: BinaryHeap<Reverse<Total>>,
+}
+
+// f64 isn't Ord (NaN breaks ordering), so BinaryHeap can't hold it directly.
+// This wrapper orders by f64::total_cmp; NaN is rejected in add().
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Total(f64);
+
+impl Eq for Total {}
+
+impl PartialOrd for Total {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Total {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(&other.0)
+    }
+}
+
+impl RunningMedian {
+    pub fn new() -> Self {
+        RunningMedian::default()
+    }
+
+    // Panics on NaN — it has no place in an ordering
+    pub fn add(&mut self, x: f64) {
+        assert!(!x.is_nan(), "RunningMedian cannot accept NaN");
+
+        // Route to the correct half...
+        match self.lower.peek() {
+            Some(&Total(top)) if x > top => self.upper.push(Reverse(Total(x))),
+            _ => self.lower.push(Total(x)),
+        }
+
+        // ...then move one item across if the sizes drifted apart
+        if self.lower.len() > self.upper.len() + 1 {
+            let moved = self.lower.pop().unwrap();
+            self.upper.push(Reverse(moved));
+        } else if self.upper.len() > self.lower.len() {
+            let Reverse(moved) = self.upper.pop().unwrap();
+            self.lower.push(moved);
+        }
+    }
+
+    pub fn median(&self) -> Option<f64> {
+        let Total(low) = *self.lower.peek()?;
+        if self.lower.len() > self.upper.len() {
+            Some(low)
+        } else {
+            let Reverse(Total(high)) = *self.upper.peek()?;
+            Some((low + high) / 2.0)
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.lower.len() + self.upper.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lower.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_has_no_median() {
+        let m = RunningMedian::new();
+        assert_eq!(m.median(), None);
+        assert!(m.is_empty());
+    }
+
+    #[test]
+    fn one_value() {
+        let mut m = RunningMedian::new();
+        m.add(7.0);
+        assert_eq!(m.median(), Some(7.0));
+    }
+
+    #[test]
+    fn two_values_average() {
+        let mut m = RunningMedian::new();
+        m.add(10.0);
+        m.add(3.0);
+        assert_eq!(m.median(), Some(6.5));
+    }
+
+    #[test]
+    fn odd_count_is_middle_value() {
+        let mut m = RunningMedian::new();
+        for x in [9.0, 1.0, 5.0, 3.0, 7.0] {
+            m.add(x);
+        }
+        assert_eq!(m.median(), Some(5.0));
+        assert_eq!(m.len(), 5);
+    }
+
+    #[test]
+    fn known_running_sequence() {
+        let mut m = RunningMedian::new();
+        let medians: Vec<f64> = [
+            5.0, 15.0, 1.0, 3.0, 2.0, 8.0, 7.0, 9.0, 10.0, 6.0, 11.0, 4.0,
+        ]
+        .into_iter()
+        .map(|x| {
+            m.add(x);
+            m.median().unwrap()
+        })
+        .collect();
+        assert_eq!(
+            medians,
+            vec![5.0, 10.0, 5.0, 4.0, 3.0, 4.0, 5.0, 6.0, 7.0, 6.5, 7.0, 6.5]
+        );
+    }
+
+    #[test]
+    fn duplicates_and_negatives() {
+        let mut m = RunningMedian::new();
+        for x in [-2.0, -2.0, -2.0, 4.0] {
+            m.add(x);
+        }
+        assert_eq!(m.median(), Some(-2.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "NaN")]
+    fn nan_panics() {
+        RunningMedian::new().add(f64::NAN);
+    }
+}
@@ -0,0 +1,97 @@
+// Overflow-aware arithmetic.
+// Plain `+` panics on overflow in debug builds and silently wraps in release.
+// These helpers make the choice explicit.
+
+// === u8 ===
+
+// None if the result doesn't fit
+pub fn checked_add_u8(a: u8, b: u8) -> Option<u8> {
+    a.checked_add(b)
+}
+
+// Clamps at u8::MAX (or u8::MIN)
+pub fn saturating_add_u8(a: u8, b: u8) -> u8 {
+    a.saturating_add(b)
+}
+
+// Wraps around modulo 256
+pub fn wrapping_add_u8(a: u8, b: u8) -> u8 {
+    a.wrapping_add(b)
+}
+
+// Wrapped result plus a flag saying whether it overflowed
+pub fn overflowing_add_u8(a: u8, b: u8) -> (u8, bool) {
+    a.overflowing_add(b)
+}
+
+// === i32 ===
+
+pub fn checked_add_i32(a: i32, b: i32) -> Option<i32> {
+    a.checked_add(b)
+}
+
+pub fn saturating_add_i32(a: i32, b: i32) -> i32 {
+    a.saturating_add(b)
+}
+
+pub fn wrapping_add_i32(a: i32, b: i32) -> i32 {
+    a.wrapping_add(b)
+}
+
+pub fn overflowing_add_i32(a: i32, b: i32) -> (i32, bool) {
+    a.overflowing_add(b)
+}
+
+// === FOLDING ===
+
+// Sums the slice, returning None as soon as any step overflows
+pub fn sum_checked(xs: &[i64]) -> Option<i64> {
+    xs.iter().try_fold(0i64, |acc, &x| acc.checked_add(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saturating_stops_at_max() {
+        assert_eq!(saturating_add_u8(u8::MAX, 1), 255);
+    }
+
+    #[test]
+    fn wrapping_goes_to_zero() {
+        assert_eq!(wrapping_add_u8(u8::MAX, 1), 0);
+    }
+
+    #[test]
+    fn checked_returns_none_on_overflow() {
+        assert_eq!(checked_add_u8(u8::MAX, 1), None);
+        assert_eq!(checked_add_u8(250, 5), Some(255));
+    }
+
+    #[test]
+    fn overflowing_reports_flag() {
+        assert_eq!(overflowing_add_u8(u8::MAX, 1), (0, true));
+        assert_eq!(overflowing_add_u8(1, 1), (2, false));
+    }
+
+    #[test]
+    fn i32_variants() {
+        assert_eq!(checked_add_i32(i32::MAX, 1), None);
+        assert_eq!(saturating_add_i32(i32::MIN, -1), i32::MIN);
+        assert_eq!(wrapping_add_i32(i32::MAX, 1), i32::MIN);
+        assert_eq!(overflowing_add_i32(i32::MAX, 1), (i32::MIN, true));
+    }
+
+    #[test]
+    fn sum_checked_ok() {
+        assert_eq!(sum_checked(&[1, 2, 3]), Some(6));
+        assert_eq!(sum_checked(&[]), Some(0));
+    }
+
+    #[test]
+    fn sum_checked_overflows() {
+        assert_eq!(sum_checked(&[i64::MAX, 1]), None);
+        assert_eq!(sum_checked(&[i64::MIN, -1]), None);
+    }
+}
@@ -0,0 +1,86 @@
+// Binary search over a sorted slice.
+//
+// Same contract as slice::binary_search:
+// - Ok(index) if `target` is present
+// - Err(index) with the position where it could be inserted to keep order
+//
+// With duplicates, std may return any matching index. This version always
+// finds the leftmost one, which is still within that contract.
+pub fn binary_search<T: Ord>(sorted: &[T], target: &T) -> Result<usize, usize> {
+    // Invariant: everything before `lo` is < target,
+    // everything from `hi` onwards is >= target
+    let mut lo = 0;
+    let mut hi = sorted.len();
+
+    while lo < hi {
+        // lo + (hi - lo) / 2 avoids overflow that (lo + hi) / 2 could hit
+        let mid = lo + (hi - lo) / 2;
+        if sorted[mid] < *target {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+
+    if lo < sorted.len() && sorted[lo] == *target {
+        Ok(lo)
+    } else {
+        Err(lo)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DATA: [i32; 5] = [10, 20, 30, 40, 50];
+
+    #[test]
+    fn finds_existing() {
+        assert_eq!(binary_search(&DATA, &10), Ok(0));
+        assert_eq!(binary_search(&DATA, &30), Ok(2));
+        assert_eq!(binary_search(&DATA, &50), Ok(4));
+    }
+
+    #[test]
+    fn insertion_point_in_middle() {
+        assert_eq!(binary_search(&DATA, &25), Err(2));
+    }
+
+    #[test]
+    fn insertion_point_before_first() {
+        assert_eq!(binary_search(&DATA, &1), Err(0));
+    }
+
+    #[test]
+    fn insertion_point_after_last() {
+        assert_eq!(binary_search(&DATA, &99), Err(5));
+    }
+
+    #[test]
+    fn empty_slice() {
+        let empty: [i32; 0] = [];
+        assert_eq!(binary_search(&empty, &7), Err(0));
+    }
+
+    #[test]
+    fn duplicates() {
+        let data = [1, 2, 2, 2, 3];
+        let idx = binary_search(&data, &2).unwrap();
+        assert_eq!(data[idx], 2);
+        assert_eq!(idx, 1); // leftmost
+    }
+
+    #[test]
+    fn agrees_with_std() {
+        let data: Vec<i32> = (0..20).map(|n| n * 3).collect();
+        for target in -2..62 {
+            let ours = binary_search(&data, &target);
+            let std = data.binary_search(&target);
+            assert_eq!(ours.is_ok(), std.is_ok(), "target {}", target);
+            if ours.is_err() {
+                assert_eq!(ours, std, "target {}", target);
+            }
+        }
+    }
+}
@@ -0,0 +1,124 @@
+// Segment tree for range sums with point updates, both O(log n).
+//
+// Iterative, array-backed layout: for n values the tree has 2n slots.
+// Leaves live at tree[n..2n]; each internal node i holds the sum of
+// its children at 2i and 2i + 1. The root is tree[1] (tree[0] is unused).
+//
+//              [1]=10
+//          /          \
+//      [2]=3          [3]=7        values: [1, 2, 3, 4]
+//      /    \         /    \
+//   [4]=1 [5]=2    [6]=3 [7]=4
+#[derive(Debug, Clone)]
+pub struct SegmentTree {
+    n: usize,
+    tree: Vec<i64>,
+}
+
+impl SegmentTree {
+    pub fn new(values: &[i64]) -> SegmentTree {
+        let n = values.len();
+        let mut tree = vec![0; 2 * n];
+        tree[n..].copy_from_slice(values);
+        // Fill parents from the bottom up
+        for i in (1..n).rev() {
+            tree[i] = tree[2 * i] + tree[2 * i + 1];
+        }
+        SegmentTree { n, tree }
+    }
+
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    // Sum of values[l..r] (half-open, like slice ranges)
+    pub fn query(&self, l: usize, r: usize) -> i64 {
+        assert!(l <= r && r <= self.n, "range {}..{} out of bounds for length {}", l, r, self.n);
+        let mut sum = 0;
+        let (mut l, mut r) = (l + self.n, r + self.n);
+        // Walk both edges up the tree. An edge node that is the "wrong"
+        // child of its parent (l odd / r odd) is only partly covered by
+        // the parent, so take it now and step inward.
+        while l < r {
+            if l % 2 == 1 {
+                sum += self.tree[l];
+                l += 1;
+            }
+            if r % 2 == 1 {
+                r -= 1;
+                sum += self.tree[r];
+            }
+            l /= 2;
+            r /= 2;
+        }
+        sum
+    }
+
+    // Sets values[i] = value and fixes every ancestor's sum
+    pub fn update(&mut self, i: usize, value: i64) {
+        assert!(i < self.n, "index {} out of bounds for length {}", i, self.n);
+        let mut pos = i + self.n;
+        self.tree[pos] = value;
+        while pos > 1 {
+            pos /= 2;
+            self.tree[pos] = self.tree[2 * pos] + self.tree[2 * pos + 1];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn brute(values: &[i64], l: usize, r: usize) -> i64 {
+        values[l..r].iter().sum()
+    }
+
+    #[test]
+    fn initial_range_sums() {
+        let values = [5, -2, 7, 1, 3, 8, -4];
+        let tree = SegmentTree::new(&values);
+        for l in 0..=values.len() {
+            for r in l..=values.len() {
+                assert_eq!(tree.query(l, r), brute(&values, l, r), "range {}..{}", l, r);
+            }
+        }
+    }
+
+    #[test]
+    fn updates_are_reflected() {
+        let mut values = vec![1, 2, 3, 4, 5];
+        let mut tree = SegmentTree::new(&values);
+        tree.update(2, 10);
+        values[2] = 10;
+        assert_eq!(tree.query(0, 5), 22);
+        assert_eq!(tree.query(1, 4), brute(&values, 1, 4));
+        tree.update(0, -1);
+        assert_eq!(tree.query(0, 2), 1);
+    }
+
+    #[test]
+    fn single_element_ranges() {
+        let tree = SegmentTree::new(&[4, 9, 2]);
+        assert_eq!(tree.query(0, 1), 4);
+        assert_eq!(tree.query(1, 2), 9);
+        assert_eq!(tree.query(2, 3), 2);
+        assert_eq!(tree.query(1, 1), 0); // empty range
+    }
+
+    #[test]
+    fn full_range() {
+        let tree = SegmentTree::new(&[1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(tree.query(0, tree.len()), 36);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of bounds")]
+    fn query_past_end_panics() {
+        SegmentTree::new(&[1, 2]).query(0, 3);
+    }
+}
@@ -0,0 +1,130 @@
+use std::f64::consts::PI;
+
+// Object-safe: no generics, no `Self` return types, so `dyn Shape` works
+pub trait Shape {
+    fn area(&self) -> f64;
+    fn perimeter(&self) -> f64;
+
+    fn name(&self) -> &str {
+        "shape"
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Circle {
+    pub radius: f64,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Rectangle {
+    pub width: f64,
+    pub height: f64,
+}
+
+// Defined by its three side lengths
+#[derive(Debug, Clone, Copy)]
+pub struct Triangle {
+    pub a: f64,
+    pub b: f64,
+    pub c: f64,
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        PI * self.radius * self.radius
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * PI * self.radius
+    }
+
+    fn name(&self) -> &str {
+        "circle"
+    }
+}
+
+impl Shape for Rectangle {
+    fn area(&self) -> f64 {
+        self.width * self.height
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * (self.width + self.height)
+    }
+
+    fn name(&self) -> &str {
+        "rectangle"
+    }
+}
+
+impl Shape for Triangle {
+    // Heron's formula. For a degenerate triangle (a + b == c) the product
+    // is zero — or slightly negative from rounding — so clamp before sqrt
+    // to get 0.0 instead of NaN.
+    fn area(&self) -> f64 {
+        let s = self.perimeter() / 2.0;
+        let product = s * (s - self.a) * (s - self.b) * (s - self.c);
+        product.max(0.0).sqrt()
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.a + self.b + self.c
+    }
+
+    fn name(&self) -> &str {
+        "triangle"
+    }
+}
+
+pub fn total_area(shapes: &[Box<dyn Shape>]) -> f64 {
+    shapes.iter().map(|s| s.area()).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn circle() {
+        let c = Circle { radius: 2.0 };
+        assert!(close(c.area(), 4.0 * PI));
+        assert!(close(c.perimeter(), 4.0 * PI));
+    }
+
+    #[test]
+    fn rectangle() {
+        let r = Rectangle { width: 3.0, height: 4.0 };
+        assert!(close(r.area(), 12.0));
+        assert!(close(r.perimeter(), 14.0));
+    }
+
+    #[test]
+    fn triangle() {
+        // 3-4-5 right triangle: area = 3 * 4 / 2
+        let t = Triangle { a: 3.0, b: 4.0, c: 5.0 };
+        assert!(close(t.area(), 6.0));
+        assert!(close(t.perimeter(), 12.0));
+    }
+
+    #[test]
+    fn degenerate_triangle_has_zero_area() {
+        let flat = Triangle { a: 1.0, b: 2.0, c: 3.0 };
+        assert_eq!(flat.area(), 0.0);
+        assert!(close(flat.perimeter(), 6.0));
+    }
+
+    #[test]
+    fn total_area_of_mixed_shapes() {
+        let shapes: Vec<Box<dyn Shape>> = vec![
+            Box::new(Rectangle { width: 2.0, height: 5.0 }),
+            Box::new(Triangle { a: 3.0, b: 4.0, c: 5.0 }),
+            Box::new(Circle { radius: 1.0 }),
+        ];
+        assert!(close(total_area(&shapes), 10.0 + 6.0 + PI));
+        assert_eq!(total_area(&[]), 0.0);
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+
+// A vector that only stores its non-zero entries.
+// A million-element vector with three values set costs three map
+// entries instead of eight megabytes of zeros.
+#[derive(Debug, Clone)]
+pub struct SparseVec {
+    data: HashMap<usize, f64>,
+    len: usize,
+}
+
+impl SparseVec {
+    // All zeros, nothing stored
+    pub fn new(len: usize) -> SparseVec {
+        SparseVec { data: HashMap::new(), len }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // How many entries are actually stored
+    pub fn non_zero_count(&self) -> usize {
+        self.data.len()
+    }
+
+    // Absent entries read as 0.0; panics past the end, like Vec indexing
+    pub fn get(&self, i: usize) -> f64 {
+        self.check_index(i);
+        self.data.get(&i).copied().unwrap_or(0.0)
+    }
+
+    // Writing 0.0 removes the entry so storage stays sparse
+    pub fn set(&mut self, i: usize, v: f64) {
+        self.check_index(i);
+        if v == 0.0 {
+            self.data.remove(&i);
+        } else {
+            self.data.insert(i, v);
+        }
+    }
+
+    // Only indices present in both vectors contribute, so iterate the
+    // smaller map and look up in the larger one
+    pub fn dot(&self, other: &SparseVec) -> f64 {
+        assert_eq!(self.len, other.len, "dot product of vectors with different lengths");
+        let (small, large) = if self.data.len() <= other.data.len() {
+            (&self.data, &other.data)
+        } else {
+            (&other.data, &self.data)
+        };
+        small
+            .iter()
+            .filter_map(|(i, a)| large.get(i).map(|b| a * b))
+            .sum()
+    }
+
+    fn check_index(&self, i: usize) {
+        assert!(i < self.len, "index out of bounds: the len is {} but the index is {}", self.len, i);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_then_get() {
+        let mut v = SparseVec::new(10);
+        v.set(3, 2.5);
+        assert_eq!(v.get(3), 2.5);
+        assert_eq!(v.get(4), 0.0);
+    }
+
+    #[test]
+    fn setting_zero_removes_storage() {
+        let mut v = SparseVec::new(10);
+        v.set(3, 2.5);
+        assert_eq!(v.non_zero_count(), 1);
+        v.set(3, 0.0);
+        assert_eq!(v.non_zero_count(), 0);
+        assert_eq!(v.get(3), 0.0);
+    }
+
+    #[test]
+    fn dot_product() {
+        let mut a = SparseVec::new(100);
+        let mut b = SparseVec::new(100);
+        a.set(1, 2.0);
+        a.set(50, 3.0);
+        b.set(50, 4.0);
+        b.set(99, 5.0);
+        // Only index 50 overlaps
+        assert_eq!(a.dot(&b), 12.0);
+        assert_eq!(b.dot(&a), 12.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn get_out_of_range_panics() {
+        let v = SparseVec::new(5);
+        v.get(5);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn set_out_of_range_panics() {
+        let mut v = SparseVec::new(5);
+        v.set(10, 1.0);
+    }
+}
@@ -0,0 +1,40 @@
+// UTF-8 aware string reversal.
+//
+// Why not reverse the bytes? A String is UTF-8, so 'é' is two bytes
+// (0xC3 0xA9) and '🦀' is four. Reversing the bytes puts those
+// sequences back-to-front, which is no longer valid UTF-8 — you'd get
+// garbage (or a panic from String::from_utf8(...).unwrap()).
+//
+// Reversing by `char` keeps each code point intact. It still isn't
+// perfect: a grapheme cluster (what a user sees as one "character")
+// can be several chars. A flag like 🇯🇵 is two regional-indicator chars,
+// and "e" + combining accent is two chars — both get split apart here.
+// Handling those properly needs a grapheme crate (unicode-segmentation).
+
+pub fn reverse_chars(s: &str) -> String {
+    s.chars().rev().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reverses_ascii() {
+        assert_eq!(reverse_chars("hello"), "olleh");
+    }
+
+    #[test]
+    fn reverses_multibyte() {
+        let reversed = reverse_chars("héllo🦀");
+        assert_eq!(reversed, "🦀olléh");
+        // Same bytes in total, and still valid UTF-8 (it's a String)
+        assert_eq!(reversed.len(), "héllo🦀".len());
+        assert_eq!(reverse_chars(&reversed), "héllo🦀");
+    }
+
+    #[test]
+    fn reverses_empty() {
+        assert_eq!(reverse_chars(""), "");
+    }
+}
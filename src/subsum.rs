@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+// Finds two positions whose values add up to `target`, as (i, j) with i < j.
+//
+// The brute-force version checks every pair: O(n²). Instead, walk once
+// and remember where each value was seen. For each x, the partner we
+// need is `target - x` — one HashMap lookup tells us if it came earlier.
+pub fn two_sum(nums: &[i32], target: i32) -> Option<(usize, usize)> {
+    let mut seen: HashMap<i32, usize> = HashMap::new();
+    for (j, &x) in nums.iter().enumerate() {
+        // checked_sub: a partner outside i32's range can't be in the slice
+        if let Some(&i) = target.checked_sub(x).and_then(|need| seen.get(&need)) {
+            return Some((i, j));
+        }
+        // Keep the first index for a repeated value, so answers lean left
+        seen.entry(x).or_insert(j);
+    }
+    None
+}
+
+// Largest sum of any non-empty run of consecutive elements (Kadane).
+//
+// `best_here` is the best sum of a run *ending at* the current element:
+// either extend the previous run or start fresh here, whichever is
+// bigger. The answer is the best of those seen anywhere. All-negative
+// input gives the single least-negative element, since a run can't be
+// empty.
+//
+// None when there's no answer to give: an empty slice has no run to
+// sum, and the best sum may not fit in an i32 ([i32::MAX, 1] doesn't).
+// The running sums are kept in i64 so that overflow is caught here
+// rather than panicking or wrapping halfway through.
+pub fn max_subarray(nums: &[i32]) -> Option<i32> {
+    let (&first, rest) = nums.split_first()?;
+    let mut best_here = i64::from(first);
+    let mut best = best_here;
+    for &x in rest {
+        let x = i64::from(x);
+        best_here = x.max(best_here + x);
+        best = best.max(best_here);
+    }
+    i32::try_from(best).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn two_sum_finds_pair() {
+        assert_eq!(two_sum(&[2, 7, 11, 15], 9), Some((0, 1)));
+        assert_eq!(two_sum(&[3, 2, 4], 6), Some((1, 2)));
+        assert_eq!(two_sum(&[-3, 4, 3, 90], 0), Some((0, 2)));
+    }
+
+    #[test]
+    fn two_sum_none_when_no_pair() {
+        assert_eq!(two_sum(&[1, 2, 3], 100), None);
+        assert_eq!(two_sum(&[5], 10), None); // an element can't pair with itself
+        assert_eq!(two_sum(&[], 0), None);
+        assert_eq!(two_sum(&[i32::MIN, 1], i32::MAX), None);
+    }
+
+    #[test]
+    fn two_sum_duplicates() {
+        assert_eq!(two_sum(&[3, 3], 6), Some((0, 1)));
+        assert_eq!(two_sum(&[1, 5, 5, 5], 10), Some((1, 2)));
+    }
+
+    #[test]
+    fn max_subarray_mixed() {
+        // [4, -1, 2, 1] sums to 6
+        assert_eq!(max_subarray(&[-2, 1, -3, 4, -1, 2, 1, -5, 4]), Some(6));
+        assert_eq!(max_subarray(&[5, -9, 6]), Some(6));
+    }
+
+    #[test]
+    fn max_subarray_all_negative() {
+        assert_eq!(max_subarray(&[-8, -3, -6, -2, -5]), Some(-2));
+    }
+
+    #[test]
+    fn max_subarray_overflow_is_none() {
+        assert_eq!(max_subarray(&[i32::MAX, 1]), None);
+        assert_eq!(max_subarray(&[i32::MAX, i32::MAX]), None);
+        // Intermediate sums past i32 are fine if the best run fits
+        assert_eq!(max_subarray(&[i32::MAX, -5, 3]), Some(i32::MAX));
+        assert_eq!(max_subarray(&[i32::MIN, i32::MIN]), Some(i32::MIN));
+    }
+
+    #[test]
+    fn max_subarray_empty_is_none() {
+        assert_eq!(max_subarray(&[]), None);
+    }
+}
@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+// Replaces `{name}` placeholders with values from `vars`.
+// `{{` and `}}` produce literal braces.
+//
+// Errors on:
+// - a placeholder whose name isn't in `vars`
+// - a `{` that never gets closed
+// - a lone `}` with no matching `{`
+pub fn render(template: &str, vars: &HashMap<String, String>) -> Result<String, String> {
+    let mut out = String::with_capacity(template.len());
+    let mut chars = template.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                out.push('{');
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(ch) => name.push(ch),
+                        None => return Err(format!("unclosed brace in placeholder '{{{}'", name)),
+                    }
+                }
+                match vars.get(name.trim()) {
+                    Some(value) => out.push_str(value),
+                    None => return Err(format!("unknown variable '{}'", name.trim())),
+                }
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                out.push('}');
+            }
+            '}' => return Err(String::from("unmatched '}' (use '}}' for a literal brace)")),
+            _ => out.push(c),
+        }
+    }
+
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect()
+    }
+
+    #[test]
+    fn substitutes_placeholders() {
+        let v = vars(&[("name", "Alice"), ("age", "30")]);
+        assert_eq!(
+            render("Hello {name}, you are {age}", &v),
+            Ok(String::from("Hello Alice, you are 30"))
+        );
+    }
+
+    #[test]
+    fn unknown_variable_is_error() {
+        let v = vars(&[("name", "Alice")]);
+        let err = render("Hi {nme}", &v).unwrap_err();
+        assert!(err.contains("nme"), "got: {}", err);
+    }
+
+    #[test]
+    fn unclosed_brace_is_error() {
+        let v = vars(&[("name", "Alice")]);
+        let err = render("Hi {name", &v).unwrap_err();
+        assert!(err.contains("unclosed"), "got: {}", err);
+    }
+
+    #[test]
+    fn escaped_braces_are_literal() {
+        let v = vars(&[("x", "1")]);
+        assert_eq!(render("{{x}} = {x}", &v), Ok(String::from("{x} = 1")));
+        assert_eq!(render("}}{{", &v), Ok(String::from("}{")));
+    }
+
+    #[test]
+    fn lone_closing_brace_is_error() {
+        assert!(render("oops }", &vars(&[])).is_err());
+    }
+}
@@ -0,0 +1,136 @@
+// Turns arithmetic text like "12 + (3.5 * 4)" into a list of tokens.
+// Errors carry the byte offset of the offending character so callers
+// can point at it.
+
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Token {
+    Number(f64),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    LParen,
+    RParen,
+}
+
+pub fn tokenize(input: &str) -> Result<Vec<Token>, (usize, String)> {
+    let mut tokens = Vec::new();
+    let mut chars = input.char_indices().peekable();
+
+    while let Some(&(pos, c)) = chars.peek() {
+        let token = match c {
+            c if c.is_whitespace() => {
+                chars.next();
+                continue;
+            }
+            '0'..='9' | '.' => {
+                tokens.push(read_number(input, &mut chars)?);
+                continue;
+            }
+            '+' => Token::Plus,
+            '-' => Token::Minus,
+            '*' => Token::Star,
+            '/' => Token::Slash,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            other => return Err((pos, format!("unexpected character '{}'", other))),
+        };
+        tokens.push(token);
+        chars.next();
+    }
+
+    Ok(tokens)
+}
+
+// Consumes digits with at most one '.', e.g. "42", "3.5", ".5"
+fn read_number(input: &str, chars: &mut Peekable<CharIndices>) -> Result<Token, (usize, String)> {
+    let start = chars.peek().map_or(0, |&(pos, _)| pos);
+    let mut end = start;
+    let mut seen_dot = false;
+
+    while let Some(&(pos, c)) = chars.peek() {
+        match c {
+            '0'..='9' => {}
+            '.' if !seen_dot => seen_dot = true,
+            '.' => return Err((pos, String::from("second decimal point in number"))),
+            _ => break,
+        }
+        end = pos + c.len_utf8();
+        chars.next();
+    }
+
+    let text = &input[start..end];
+    if text == "." {
+        return Err((start, String::from("'.' is not a number")));
+    }
+    // "3." and ".5" both parse fine as f64
+    text.parse::<f64>()
+        .map(Token::Number)
+        .map_err(|e| (start, format!("invalid number '{}': {}", text, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_expression() {
+        assert_eq!(
+            tokenize("12 + (3.5 * 4)"),
+            Ok(vec![
+                Token::Number(12.0),
+                Token::Plus,
+                Token::LParen,
+                Token::Number(3.5),
+                Token::Star,
+                Token::Number(4.0),
+                Token::RParen,
+            ])
+        );
+    }
+
+    #[test]
+    fn no_whitespace_needed() {
+        assert_eq!(
+            tokenize("1-2/3"),
+            Ok(vec![
+                Token::Number(1.0),
+                Token::Minus,
+                Token::Number(2.0),
+                Token::Slash,
+                Token::Number(3.0),
+            ])
+        );
+    }
+
+    #[test]
+    fn unexpected_character_position() {
+        let (pos, msg) = tokenize("1 + x").unwrap_err();
+        assert_eq!(pos, 4);
+        assert!(msg.contains("'x'"));
+
+        // Positions are byte offsets, so multibyte chars shift them
+        let (pos, _) = tokenize("é + 1").unwrap_err();
+        assert_eq!(pos, 0);
+        let (pos, _) = tokenize("1 + é").unwrap_err();
+        assert_eq!(pos, 4);
+    }
+
+    #[test]
+    fn malformed_number() {
+        let (pos, msg) = tokenize("1.2.3").unwrap_err();
+        assert_eq!(pos, 3);
+        assert!(msg.contains("decimal point"));
+
+        let (pos, _) = tokenize("2 * .").unwrap_err();
+        assert_eq!(pos, 4);
+    }
+
+    #[test]
+    fn empty_input() {
+        assert_eq!(tokenize("   "), Ok(vec![]));
+    }
+}
@@ -0,0 +1,65 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+// The k largest items, largest first, in O(n log k).
+//
+// Sorting everything costs O(n log n) even when you only want the top 3.
+// Instead keep a min-heap of the best k seen so far: its top is the
+// *weakest* of them. Each new item only has to beat that one to get in,
+// and the heap never grows past k.
+//
+// BinaryHeap is a max-heap, so items are wrapped in Reverse to flip it.
+pub fn k_largest<T: Ord + Clone>(items: &[T], k: usize) -> Vec<T> {
+    if k == 0 {
+        return Vec::new();
+    }
+
+    let mut heap: BinaryHeap<Reverse<T>> = BinaryHeap::with_capacity(k + 1);
+    for item in items {
+        if heap.len() < k {
+            heap.push(Reverse(item.clone()));
+        } else if let Some(Reverse(weakest)) = heap.peek()
+            && item > weakest
+        {
+            heap.pop();
+            heap.push(Reverse(item.clone()));
+        }
+    }
+
+    // into_sorted_vec is ascending by Reverse<T>, i.e. descending by T
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(item)| item)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn k_smaller_than_len() {
+        assert_eq!(k_largest(&[5, 1, 9, 3, 7, 2], 3), vec![9, 7, 5]);
+    }
+
+    #[test]
+    fn k_equal_to_len_returns_all_sorted() {
+        assert_eq!(k_largest(&[2, 3, 1], 3), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn k_larger_than_len_returns_all() {
+        assert_eq!(k_largest(&["b", "a"], 10), vec!["b", "a"]);
+    }
+
+    #[test]
+    fn k_zero_is_empty() {
+        assert!(k_largest(&[1, 2, 3], 0).is_empty());
+        assert!(k_largest::<i32>(&[], 3).is_empty());
+    }
+
+    #[test]
+    fn duplicates_kept() {
+        assert_eq!(k_largest(&[4, 8, 8, 1, 8, 4], 4), vec![8, 8, 8, 4]);
+    }
+}
@@ -0,0 +1,109 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+// Orders nodes so every edge (a, b) has `a` before `b` — "a must happen
+// before b". Uses Kahn's algorithm:
+//
+// 1. Count incoming edges (in-degree) for every node
+// 2. Collect the nodes with nothing left to wait for (in-degree 0)
+// 3. Take one, emit it, and decrement each of its successors; any that
+//    drop to 0 become ready too
+//
+// If nodes remain unemitted at the end, they're stuck waiting on each
+// other — a cycle.
+//
+// The ready set is a min-heap (BinaryHeap of Reverse), so the smallest
+// ready node is always taken next — even one that became ready after
+// larger ones. The same input always gives the same output.
+pub fn toposort(edges: &[(u32, u32)]) -> Result<Vec<u32>, String> {
+    let mut in_degree: HashMap<u32, usize> = HashMap::new();
+    let mut successors: HashMap<u32, Vec<u32>> = HashMap::new();
+
+    for &(a, b) in edges {
+        in_degree.entry(a).or_insert(0);
+        *in_degree.entry(b).or_insert(0) += 1;
+        successors.entry(a).or_default().push(b);
+    }
+
+    let mut ready: BinaryHeap<Reverse<u32>> =
+        in_degree.iter().filter(|&(_, &d)| d == 0).map(|(&n, _)| Reverse(n)).collect();
+
+    let mut order = Vec::with_capacity(in_degree.len());
+    while let Some(Reverse(node)) = ready.pop() {
+        order.push(node);
+
+        for &next in successors.get(&node).into_iter().flatten() {
+            let degree = in_degree.get_mut(&next).expect("every successor has an in-degree");
+            *degree -= 1;
+            if *degree == 0 {
+                ready.push(Reverse(next));
+            }
+        }
+    }
+
+    if order.len() < in_degree.len() {
+        let mut stuck: Vec<u32> = in_degree.into_iter().filter(|&(_, d)| d > 0).map(|(n, _)| n).collect();
+        stuck.sort_unstable();
+        return Err(format!("dependency cycle among nodes {:?}", stuck));
+    }
+    Ok(order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Checks that every edge is respected by the ordering
+    fn respects(order: &[u32], edges: &[(u32, u32)]) -> bool {
+        let pos = |n| order.iter().position(|&x| x == n).unwrap();
+        edges.iter().all(|&(a, b)| pos(a) < pos(b))
+    }
+
+    #[test]
+    fn linear_chain() {
+        let edges = [(3, 4), (1, 2), (2, 3)];
+        assert_eq!(toposort(&edges), Ok(vec![1, 2, 3, 4]));
+    }
+
+    #[test]
+    fn diamond() {
+        //   1
+        //  / \
+        // 2   3
+        //  \ /
+        //   4
+        let edges = [(1, 2), (1, 3), (2, 4), (3, 4)];
+        let order = toposort(&edges).unwrap();
+        assert_eq!(order, vec![1, 2, 3, 4]);
+        assert!(respects(&order, &edges));
+    }
+
+    #[test]
+    fn independent_nodes() {
+        let edges = [(10, 11), (1, 2)];
+        let order = toposort(&edges).unwrap();
+        // 2 becomes ready after 10, but is smaller, so it goes first
+        assert_eq!(order, vec![1, 2, 10, 11]);
+        assert!(respects(&order, &edges));
+    }
+
+    #[test]
+    fn cycle_is_error() {
+        let err = toposort(&[(1, 2), (2, 3), (3, 1), (0, 1)]).unwrap_err();
+        assert!(err.contains("cycle"), "got: {}", err);
+        assert!(toposort(&[(5, 5)]).is_err());
+    }
+
+    #[test]
+    fn no_edges() {
+        assert_eq!(toposort(&[]), Ok(vec![]));
+    }
+
+    #[test]
+    fn output_is_deterministic() {
+        let edges = [(5, 9), (3, 9), (1, 9), (7, 9)];
+        for _ in 0..10 {
+            assert_eq!(toposort(&edges), Ok(vec![1, 3, 5, 7, 9]));
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+// A state machine whose rules are data rather than code.
+//
+// Instead of a hand-written `match (state, event)`, the allowed moves
+// live in a HashMap keyed by (current state, event). Firing an event
+// looks up that pair: found → move to the target state; missing → the
+// event isn't allowed here, and the state stays put.
+//
+// Because the table is built at runtime, the same engine can run a door,
+// a traffic light or an order workflow — or rules loaded from a file.
+#[derive(Debug, Clone)]
+pub struct StateMachine<S: Eq + Hash + Clone, E: Eq + Hash + Clone> {
+    current: S,
+    rules: HashMap<(S, E), S>,
+}
+
+impl<S: Eq + Hash + Clone, E: Eq + Hash + Clone> StateMachine<S, E> {
+    pub fn new(initial: S) -> Self {
+        StateMachine {
+            current: initial,
+            rules: HashMap::new(),
+        }
+    }
+
+    // Allows `event` to move the machine from `from` to `to`. Adding the
+    // same (from, event) twice replaces the earlier target.
+    pub fn add_transition(&mut self, from: S, event: E, to: S) {
+        self.rules.insert((from, event), to);
+    }
+
+    pub fn current(&self) -> &S {
+        &self.current
+    }
+
+    pub fn can_fire(&self, event: &E) -> bool {
+        // The key is an owned tuple, so build one to look up with
+        self.rules
+            .contains_key(&(self.current.clone(), event.clone()))
+    }
+}
+
+impl<S, E> StateMachine<S, E>
+where
+    S: Eq + Hash + Clone + Debug,
+    E: Eq + Hash + Clone + Debug,
+{
+    // Applies `event` and returns the new state. A disallowed event is an
+    // Err and leaves the current state unchanged.
+    pub fn fire(&mut self, event: E) -> Result<&S, String> {
+        let key = (self.current.clone(), event);
+        match self.rules.get(&key) {
+            Some(next) => {
+                self.current = next.clone();
+                Ok(&self.current)
+            }
+            None => Err(format!(
+                "event {:?} is not allowed in state {:?}",
+                key.1, key.0
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    enum Light {
+        Red,
+        Green,
+        Yellow,
+    }
+
+    fn traffic_light() -> StateMachine<Light, &'static str> {
+        let mut m = StateMachine::new(Light::Red);
+        m.add_transition(Light::Red, "go", Light::Green);
+        m.add_transition(Light::Green, "slow", Light::Yellow);
+        m.add_transition(Light::Yellow, "stop", Light::Red);
+        m
+    }
+
+    #[test]
+    fn valid_sequence() {
+        let mut m = traffic_light();
+        assert_eq!(m.fire("go"), Ok(&Light::Green));
+        assert_eq!(m.fire("slow"), Ok(&Light::Yellow));
+        assert_eq!(m.fire("stop"), Ok(&Light::Red));
+        assert_eq!(m.current(), &Light::Red);
+    }
+
+    #[test]
+    fn invalid_event_keeps_state() {
+        let mut m = traffic_light();
+        let err = m.fire("slow").unwrap_err();
+        assert!(err.contains("\"slow\"") && err.contains("Red"), "{}", err);
+        assert_eq!(m.current(), &Light::Red);
+        assert!(!m.can_fire(&"slow"));
+        assert!(m.can_fire(&"go"));
+    }
+
+    #[test]
+    fn self_transition() {
+        let mut m = traffic_light();
+        m.add_transition(Light::Red, "wait", Light::Red);
+        assert_eq!(m.fire("wait"), Ok(&Light::Red));
+        assert_eq!(m.fire("wait"), Ok(&Light::Red));
+        assert_eq!(m.fire("go"), Ok(&Light::Green));
+    }
+
+    #[test]
+    fn unknown_event_errors() {
+        let mut m = traffic_light();
+        assert!(m.fire("teleport").is_err());
+    }
+}
@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+
+// Prefix tree: each node maps the next char to a child node.
+// A word is a path from the root, with `is_word` marking where it ends —
+// so "car" and "card" share the nodes for c-a-r.
+#[derive(Debug, Default)]
+pub struct Trie {
+    children: HashMap<char, Trie>,
+    is_word: bool,
+}
+
+impl Trie {
+    pub fn new() -> Trie {
+        Trie::default()
+    }
+
+    pub fn insert(&mut self, word: &str) {
+        let mut node = self;
+        for c in word.chars() {
+            node = node.children.entry(c).or_default();
+        }
+        node.is_word = true;
+    }
+
+    // True only for whole words — a prefix like "ca" of "car" doesn't count
+    pub fn contains(&self, word: &str) -> bool {
+        self.find(word).is_some_and(|node| node.is_word)
+    }
+
+    // Every stored word starting with `prefix`, sorted
+    pub fn words_with_prefix(&self, prefix: &str) -> Vec<String> {
+        let mut words = Vec::new();
+        if let Some(node) = self.find(prefix) {
+            let mut current = prefix.to_string();
+            node.collect(&mut current, &mut words);
+        }
+        words.sort();
+        words
+    }
+
+    // Follow `path` down from this node
+    fn find(&self, path: &str) -> Option<&Trie> {
+        let mut node = self;
+        for c in path.chars() {
+            node = node.children.get(&c)?;
+        }
+        Some(node)
+    }
+
+    // Depth-first walk, building each word in `current` as we go
+    fn collect(&self, current: &mut String, words: &mut Vec<String>) {
+        if self.is_word {
+            words.push(current.clone());
+        }
+        for (&c, child) in &self.children {
+            current.push(c);
+            child.collect(current, words);
+            current.pop();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Trie {
+        let mut t = Trie::new();
+        for w in ["car", "card", "care", "cat", "dog"] {
+            t.insert(w);
+        }
+        t
+    }
+
+    #[test]
+    fn membership() {
+        let t = sample();
+        assert!(t.contains("car"));
+        assert!(t.contains("dog"));
+        assert!(!t.contains("cow"));
+        assert!(!t.contains(""));
+    }
+
+    #[test]
+    fn prefix_returns_all_completions() {
+        let t = sample();
+        assert_eq!(t.words_with_prefix("car"), vec!["car", "card", "care"]);
+        assert_eq!(t.words_with_prefix("ca"), vec!["car", "card", "care", "cat"]);
+        assert_eq!(t.words_with_prefix("").len(), 5);
+    }
+
+    #[test]
+    fn unmatched_prefix_is_empty() {
+        assert!(sample().words_with_prefix("x").is_empty());
+        assert!(sample().words_with_prefix("cart").is_empty());
+    }
+
+    #[test]
+    fn prefix_is_not_a_word() {
+        let t = sample();
+        assert!(!t.contains("ca"));
+        assert!(t.contains("car"));
+        assert!(!t.contains("do"));
+    }
+
+    #[test]
+    fn unicode_words() {
+        let mut t = Trie::new();
+        t.insert("café");
+        t.insert("cafés");
+        assert_eq!(t.words_with_prefix("caf"), vec!["café", "cafés"]);
+    }
+}
@@ -0,0 +1,60 @@
+// Shortens text for display: "A very long title" → "A very lo…"
+//
+// Counts characters, not bytes, so multibyte text is never cut in the
+// middle of a char (which would panic with &s[..n]). The "…" counts
+// toward `max_chars`, so the result is never longer than asked for.
+// Short enough strings come back unchanged, with no ellipsis.
+
+const ELLIPSIS: char = '…';
+
+pub fn truncate_ellipsis(s: &str, max_chars: usize) -> String {
+    // nth(max_chars) is Some only if there are more than max_chars chars,
+    // and it stops counting there instead of walking the whole string
+    if s.chars().nth(max_chars).is_none() {
+        return s.to_string();
+    }
+    if max_chars == 0 {
+        return String::new();
+    }
+
+    let mut out: String = s.chars().take(max_chars - 1).collect();
+    out.push(ELLIPSIS);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_string_unchanged() {
+        assert_eq!(truncate_ellipsis("hi", 10), "hi");
+        assert_eq!(truncate_ellipsis("", 3), "");
+    }
+
+    #[test]
+    fn long_string_truncated() {
+        assert_eq!(truncate_ellipsis("hello world", 8), "hello w…");
+        assert_eq!(truncate_ellipsis("hello world", 8).chars().count(), 8);
+    }
+
+    #[test]
+    fn exactly_at_limit_unchanged() {
+        assert_eq!(truncate_ellipsis("hello", 5), "hello");
+    }
+
+    #[test]
+    fn multibyte_cut_on_char_boundary() {
+        assert_eq!(truncate_ellipsis("日本語のテキスト", 4), "日本語…");
+        assert_eq!(truncate_ellipsis("crab 🦀🦀🦀", 7), "crab 🦀…");
+        assert_eq!(truncate_ellipsis("héllo", 3), "hé…");
+    }
+
+    #[test]
+    fn zero_limit() {
+        assert_eq!(truncate_ellipsis("anything", 0), "");
+        assert_eq!(truncate_ellipsis("", 0), "");
+        // A limit of 1 leaves room for the ellipsis alone
+        assert_eq!(truncate_ellipsis("ab", 1), "…");
+    }
+}
@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+// A cache whose entries go stale a fixed time after they were inserted.
+//
+// Each entry stores its own expiry instant — or None when the TTL is so
+// long (e.g. Duration::MAX) that now + ttl can't be represented, which
+// means it never expires. Lookups just compare against
+// it, so an expired entry is invisible immediately but still takes up
+// memory until purge() sweeps it out — call that periodically.
+//
+// Like RateCounter, every method takes `now` instead of calling
+// Instant::now(), so tests can move time forward without sleeping.
+#[derive(Debug)]
+pub struct TtlCache<K: Eq + Hash, V> {
+    ttl: Duration,
+    entries: HashMap<K, (V, Option<Instant>)>,
+}
+
+impl<K: Eq + Hash, V> TtlCache<K, V> {
+    pub fn new(ttl: Duration) -> Self {
+        TtlCache {
+            ttl,
+            entries: HashMap::new(),
+        }
+    }
+
+    // Inserting an existing key replaces the value and restarts its clock
+    pub fn insert(&mut self, k: K, v: V, now: Instant) {
+        self.entries.insert(k, (v, now.checked_add(self.ttl)));
+    }
+
+    // None if missing or expired. An entry is live up to, but not
+    // including, its expiry instant.
+    pub fn get(&self, k: &K, now: Instant) -> Option<&V> {
+        match self.entries.get(k) {
+            Some((v, expires)) if is_live(*expires, now) => Some(v),
+            _ => None,
+        }
+    }
+
+    // Drops every expired entry; returns how many were removed
+    pub fn purge(&mut self, now: Instant) -> usize {
+        let before = self.entries.len();
+        self.entries
+            .retain(|_, (_, expires)| is_live(*expires, now));
+        before - self.entries.len()
+    }
+
+    // Stored entries, including expired ones not yet purged
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+// No expiry instant means it never expires
+fn is_live(expires: Option<Instant>, now: Instant) -> bool {
+    expires.is_none_or(|e| now < e)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn secs(s: u64) -> Duration {
+        Duration::from_secs(s)
+    }
+
+    #[test]
+    fn fresh_entry_retrievable() {
+        let t0 = Instant::now();
+        let mut cache = TtlCache::new(secs(10));
+        cache.insert("k", 1, t0);
+        assert_eq!(cache.get(&"k", t0), Some(&1));
+        assert_eq!(cache.get(&"k", t0 + secs(9)), Some(&1));
+        assert_eq!(cache.get(&"missing", t0), None);
+    }
+
+    #[test]
+    fn expired_entry_is_none() {
+        let t0 = Instant::now();
+        let mut cache = TtlCache::new(secs(10));
+        cache.insert("k", 1, t0);
+        assert_eq!(cache.get(&"k", t0 + secs(10)), None);
+        assert_eq!(cache.get(&"k", t0 + secs(60)), None);
+        // Still stored until purged
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn purge_removes_only_expired() {
+        let t0 = Instant::now();
+        let mut cache = TtlCache::new(secs(5));
+        cache.insert("old", 1, t0);
+        cache.insert("older", 2, t0);
+        cache.insert("new", 3, t0 + secs(4));
+
+        assert_eq!(cache.purge(t0 + secs(6)), 2);
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.get(&"new", t0 + secs(6)), Some(&3));
+        assert_eq!(cache.purge(t0 + secs(6)), 0);
+    }
+
+    #[test]
+    fn reinsert_refreshes_expiry() {
+        let t0 = Instant::now();
+        let mut cache = TtlCache::new(secs(10));
+        cache.insert("k", "v1", t0);
+        cache.insert("k", "v2", t0 + secs(8));
+        // Past the first expiry, within the second
+        assert_eq!(cache.get(&"k", t0 + secs(15)), Some(&"v2"));
+        assert_eq!(cache.get(&"k", t0 + secs(18)), None);
+    }
+
+    #[test]
+    fn huge_ttl_never_expires() {
+        let t0 = Instant::now();
+        let mut cache = TtlCache::new(Duration::MAX);
+        cache.insert("k", 1, t0);
+        assert_eq!(cache.get(&"k", t0 + secs(1_000_000_000)), Some(&1));
+        assert_eq!(cache.purge(t0 + secs(1_000_000_000)), 0);
+    }
+}
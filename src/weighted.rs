@@ -0,0 +1,81 @@
+// Weighted selection with the randomness supplied by the caller.
+//
+// Lay the weights end to end on a number line:
+//
+//   common (70)          rare (25)   epic (5)
+//   [0 ............ 70)[70 ..... 95)[95 .. 100)
+//
+// A roll in [0, total) lands in exactly one bucket. Taking the roll as a
+// parameter instead of calling an RNG here keeps the function pure —
+// tests can pass exact values and check the boundaries.
+//
+// Returns None for an empty list, a roll outside [0, total), or NaN.
+// Negative weights are treated as zero.
+pub fn weighted_pick<T>(items: &[(T, f64)], roll: f64) -> Option<&T> {
+    if roll.is_nan() || roll < 0.0 {
+        return None;
+    }
+    let mut cumulative = 0.0;
+    for (item, weight) in items {
+        cumulative += weight.max(0.0);
+        // Half-open buckets: a roll exactly on a boundary belongs to the
+        // next item, and a zero-weight item's bucket is empty
+        if roll < cumulative {
+            return Some(item);
+        }
+    }
+    None
+}
+
+// Sum of all (non-negative) weights — the exclusive upper bound for a roll
+pub fn total_weight<T>(items: &[(T, f64)]) -> f64 {
+    items.iter().map(|(_, w)| w.max(0.0)).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TABLE: [(&str, f64); 3] = [("a", 1.0), ("b", 2.0), ("c", 3.0)];
+
+    #[test]
+    fn roll_lands_in_each_bucket() {
+        assert_eq!(weighted_pick(&TABLE, 0.5), Some(&"a"));
+        assert_eq!(weighted_pick(&TABLE, 2.0), Some(&"b"));
+        assert_eq!(weighted_pick(&TABLE, 5.9), Some(&"c"));
+    }
+
+    #[test]
+    fn zero_roll_hits_first() {
+        assert_eq!(weighted_pick(&TABLE, 0.0), Some(&"a"));
+    }
+
+    #[test]
+    fn exact_boundary_goes_to_next() {
+        assert_eq!(weighted_pick(&TABLE, 1.0), Some(&"b"));
+        assert_eq!(weighted_pick(&TABLE, 3.0), Some(&"c"));
+    }
+
+    #[test]
+    fn zero_weight_is_unreachable() {
+        let table = [("x", 1.0), ("never", 0.0), ("y", 1.0)];
+        for i in 0..200 {
+            let roll = i as f64 / 100.0; // 0.00 ..= 1.99
+            assert_ne!(weighted_pick(&table, roll), Some(&"never"));
+        }
+    }
+
+    #[test]
+    fn out_of_range_rolls() {
+        assert_eq!(weighted_pick(&TABLE, 6.0), None);
+        assert_eq!(weighted_pick(&TABLE, -0.1), None);
+        assert_eq!(weighted_pick(&TABLE, f64::NAN), None);
+        assert_eq!(weighted_pick::<&str>(&[], 0.0), None);
+    }
+
+    #[test]
+    fn total() {
+        assert_eq!(total_weight(&TABLE), 6.0);
+        assert_eq!(total_weight(&[("neg", -5.0), ("pos", 2.0)]), 2.0);
+    }
+}
@@ -0,0 +1,79 @@
+use std::collections::VecDeque;
+
+// Maximum of every length-k window, in O(n) overall.
+//
+// The deque holds indices whose values are in decreasing order. Each
+// new value first evicts every smaller value from the back — those can
+// never be a window max again, since the new value is both larger and
+// stays in the window longer. The front is then always the current max;
+// it's dropped once it slides out of the window.
+//
+// Every index is pushed and popped at most once, hence linear time.
+pub fn sliding_max(data: &[i32], k: usize) -> Vec<i32> {
+    if k == 0 || k > data.len() {
+        return Vec::new();
+    }
+
+    let mut result = Vec::with_capacity(data.len() - k + 1);
+    let mut deque: VecDeque<usize> = VecDeque::with_capacity(k);
+
+    for (i, &value) in data.iter().enumerate() {
+        // Drop the front if it has slid out of the window [i + 1 - k, i]
+        if deque.front().is_some_and(|&front| front + k <= i) {
+            deque.pop_front();
+        }
+        // Keep values decreasing from front to back
+        while deque.back().is_some_and(|&back| data[back] <= value) {
+            deque.pop_back();
+        }
+        deque.push_back(i);
+
+        // The first full window ends at index k - 1
+        if i + 1 >= k {
+            result.push(data[deque[0]]);
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classic_example() {
+        assert_eq!(sliding_max(&[1, 3, -1, -3, 5, 3, 6, 7], 3), vec![3, 3, 5, 5, 6, 7]);
+    }
+
+    #[test]
+    fn window_of_one_is_identity() {
+        let data = [4, -2, 9, 0];
+        assert_eq!(sliding_max(&data, 1), data.to_vec());
+    }
+
+    #[test]
+    fn window_of_full_length() {
+        assert_eq!(sliding_max(&[2, 8, 1, 5], 4), vec![8]);
+    }
+
+    #[test]
+    fn zero_window_is_empty() {
+        assert!(sliding_max(&[1, 2, 3], 0).is_empty());
+    }
+
+    #[test]
+    fn window_larger_than_input_is_empty() {
+        assert!(sliding_max(&[1, 2], 3).is_empty());
+        assert!(sliding_max(&[], 1).is_empty());
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let data = [5, 1, 5, 2, 8, 8, -3, 0, 7, 6, 6, 1];
+        for k in 1..=data.len() {
+            let brute: Vec<i32> = data.windows(k).map(|w| *w.iter().max().unwrap()).collect();
+            assert_eq!(sliding_max(&data, k), brute, "k = {}", k);
+        }
+    }
+}
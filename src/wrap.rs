@@ -0,0 +1,71 @@
+// Greedy word wrap: fill each line with as many words as fit in `width`.
+//
+// - Runs of whitespace collapse to a single space
+// - Words are never split; a word longer than `width` gets its own line
+// - Width is counted in chars, not bytes, so accented text wraps correctly
+pub fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0;
+
+    for word in text.split_whitespace() {
+        let word_len = word.chars().count();
+
+        if current.is_empty() {
+            current.push_str(word);
+            current_len = word_len;
+        } else if current_len + 1 + word_len <= width {
+            current.push(' ');
+            current.push_str(word);
+            current_len += 1 + word_len;
+        } else {
+            lines.push(std::mem::take(&mut current));
+            current.push_str(word);
+            current_len = word_len;
+        }
+    }
+
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fits_on_one_line() {
+        assert_eq!(wrap("hello world", 20), vec!["hello world"]);
+    }
+
+    #[test]
+    fn wraps_at_word_boundaries() {
+        assert_eq!(
+            wrap("the quick brown fox jumps", 10),
+            vec!["the quick", "brown fox", "jumps"]
+        );
+        // A line may be exactly `width` long
+        assert_eq!(wrap("abc def", 7), vec!["abc def"]);
+    }
+
+    #[test]
+    fn over_long_word_gets_own_line() {
+        assert_eq!(
+            wrap("a supercalifragilistic word", 8),
+            vec!["a", "supercalifragilistic", "word"]
+        );
+    }
+
+    #[test]
+    fn collapses_multiple_spaces() {
+        assert_eq!(wrap("  lots   of\n\tspace  ", 20), vec!["lots of space"]);
+    }
+
+    #[test]
+    fn empty_text() {
+        assert!(wrap("", 10).is_empty());
+        assert!(wrap("   ", 10).is_empty());
+    }
+}